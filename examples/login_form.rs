@@ -26,8 +26,9 @@ fn main() -> io::Result<()> {
         .required()
         .validator(Box::new(MinLength(3)))
         .done()
-        .text("password", "Password")
+        .password("password", "Password")
         .placeholder("at least 8 characters")
+        .revealable()
         .required()
         .validator(Box::new(MinLength(8)))
         .done()
@@ -60,7 +61,10 @@ fn main() -> io::Result<()> {
     match form.result() {
         FormResult::Submitted => {
             println!("Logged in!");
-            println!("{}", serde_json::to_string_pretty(&form.to_json()).unwrap());
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&form.to_json_redacted()).unwrap()
+            );
         }
         FormResult::Cancelled => println!("Login cancelled."),
         FormResult::Active => println!("Form exited."),
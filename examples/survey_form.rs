@@ -1,4 +1,4 @@
-//! Example: Survey form showcasing selects, checkboxes, and the light theme.
+//! Example: Survey form showcasing selects, a checklist, and the light theme.
 //!
 //! Run with: `cargo run --example survey_form`
 
@@ -40,11 +40,10 @@ fn main() -> io::Result<()> {
         .required()
         .initial_value("3")
         .done()
-        .checkbox("interest_rust", "Interested in Rust")
-        .done()
-        .checkbox("interest_tui", "Interested in TUIs")
-        .done()
-        .checkbox("interest_web", "Interested in web dev")
+        .checklist("interests", "Interested in")
+        .option("rust", "Rust")
+        .option("tui", "TUIs")
+        .option("web", "Web dev")
         .done()
         .text("comments", "Comments")
         .placeholder("Anything else?")
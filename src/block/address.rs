@@ -1,5 +1,9 @@
 //! Address composite block.
 
+use std::sync::Arc;
+
+use serde_json::Value;
+
 use crate::block::Block;
 use crate::field::{Field, Select, TextInput};
 use crate::validation::rules::Pattern;
@@ -59,11 +63,19 @@ const US_STATES: &[(&str, &str)] = &[
     ("DC", "District of Columbia"),
 ];
 
-/// A composite block for US addresses.
+/// Returns the regions of a country as `(value, display)` pairs.
+type RegionsFn = Arc<dyn Fn(&str) -> Vec<(String, String)> + Send + Sync>;
+
+/// A composite block for addresses.
+///
+/// By default the block asks for a US address, with a State select and a
+/// ZIP code. [`AddressBlock::countries`] adds a Country select instead, and
+/// the Region select then lists the regions of the chosen country.
 pub struct AddressBlock {
     prefix: String,
     title: Option<String>,
     required: bool,
+    countries: Option<(Vec<(String, String)>, RegionsFn)>,
 }
 
 impl AddressBlock {
@@ -73,6 +85,7 @@ impl AddressBlock {
             prefix: prefix.into(),
             title: None,
             required: false,
+            countries: None,
         }
     }
 
@@ -88,6 +101,39 @@ impl AddressBlock {
         self
     }
 
+    /// Asks for the country, given as `(code, name)` pairs, and fills the
+    /// region select from `regions`, which receives the chosen country code.
+    /// Changing the country clears a region that it does not have. The
+    /// region is exported under the `state` key and the postal code under
+    /// `zip`, without US-specific validation.
+    ///
+    /// ```
+    /// use ratatui_form::AddressBlock;
+    ///
+    /// let block = AddressBlock::new("home").countries(
+    ///     vec![("CA", "Canada"), ("SG", "Singapore")],
+    ///     |country| match country {
+    ///         "CA" => vec![
+    ///             ("ON".to_string(), "Ontario".to_string()),
+    ///             ("QC".to_string(), "Quebec".to_string()),
+    ///         ],
+    ///         _ => Vec::new(),
+    ///     },
+    /// );
+    /// ```
+    pub fn countries(
+        mut self,
+        countries: Vec<(impl Into<String>, impl Into<String>)>,
+        regions: impl Fn(&str) -> Vec<(String, String)> + Send + Sync + 'static,
+    ) -> Self {
+        let countries = countries
+            .into_iter()
+            .map(|(code, name)| (code.into(), name.into()))
+            .collect();
+        self.countries = Some((countries, Arc::new(regions)));
+        self
+    }
+
     fn field_id(&self, name: &str) -> String {
         format!("{}_{}", self.prefix, name)
     }
@@ -106,8 +152,9 @@ impl Block for AddressBlock {
         let mut fields: Vec<Box<dyn Field>> = Vec::new();
 
         // Street Address 1
-        let mut street1 =
-            TextInput::new(self.field_id("street1"), "Street Address").placeholder("123 Main St");
+        let mut street1 = TextInput::new(self.field_id("street1"), "Street Address")
+            .placeholder("123 Main St")
+            .help("Street address or P.O. box");
         if self.required {
             street1 = street1.required();
         }
@@ -115,7 +162,8 @@ impl Block for AddressBlock {
 
         // Street Address 2
         let street2 = TextInput::new(self.field_id("street2"), "Address Line 2")
-            .placeholder("Apt, Suite, Unit, etc. (optional)");
+            .placeholder("Apt, Suite, Unit, etc. (optional)")
+            .help("Apartment, suite, unit, building or floor");
         fields.push(Box::new(street2));
 
         // City
@@ -125,8 +173,43 @@ impl Block for AddressBlock {
         }
         fields.push(Box::new(city));
 
+        if let Some((countries, regions)) = &self.countries {
+            // Country
+            let country_id = self.field_id("country");
+            let mut country = Select::new(country_id.clone(), "Country")
+                .options(countries.clone())
+                .help("Type a country name to filter the list");
+            if self.required {
+                country = country.required();
+            }
+            fields.push(Box::new(country));
+
+            // Region, listing the regions of the chosen country
+            let regions = Arc::clone(regions);
+            let mut region = Select::new(self.field_id("state"), "Region")
+                .options_from(move |values: &Value| {
+                    values[&country_id]
+                        .as_str()
+                        .map_or_else(Vec::new, |code| regions(code))
+                })
+                .help("Lists the regions of the chosen country");
+            if self.required {
+                region = region.required();
+            }
+            fields.push(Box::new(region));
+
+            // Postal Code
+            let mut zip = TextInput::new(self.field_id("zip"), "Postal Code");
+            if self.required {
+                zip = zip.required();
+            }
+            fields.push(Box::new(zip));
+            return fields;
+        }
+
         // State
-        let mut state = Select::new(self.field_id("state"), "State");
+        let mut state = Select::new(self.field_id("state"), "State")
+            .help("Type a state name to filter the list");
         for (abbr, name) in US_STATES {
             state = state.option(*abbr, format!("{} ({})", name, abbr));
         }
@@ -138,6 +221,7 @@ impl Block for AddressBlock {
         // ZIP Code
         let mut zip = TextInput::new(self.field_id("zip"), "ZIP Code")
             .placeholder("12345 or 12345-6789")
+            .help("5 digits, or ZIP+4 as 12345-6789")
             .validator(Box::new(Pattern::zip_code()));
         if self.required {
             zip = zip.required();
@@ -51,7 +51,9 @@ impl Block for ContactBlock {
         let mut fields: Vec<Box<dyn Field>> = Vec::new();
 
         // Full Name
-        let mut name = TextInput::new(self.field_id("name"), "Full Name").placeholder("John Doe");
+        let mut name = TextInput::new(self.field_id("name"), "Full Name")
+            .placeholder("John Doe")
+            .help("First and last name");
         if self.required {
             name = name.required();
         }
@@ -60,6 +62,7 @@ impl Block for ContactBlock {
         // Email
         let mut email = TextInput::new(self.field_id("email"), "Email")
             .placeholder("john@example.com")
+            .help("In the form name@example.com")
             .validator(Box::new(Email));
         if self.required {
             email = email.required();
@@ -69,6 +72,7 @@ impl Block for ContactBlock {
         // Phone
         let phone = TextInput::new(self.field_id("phone"), "Phone")
             .placeholder("(555) 123-4567")
+            .help("Include the area code")
             .validator(Box::new(Pattern::phone()));
         fields.push(Box::new(phone));
 
@@ -6,16 +6,21 @@ use ratatui::layout::Rect;
 use serde_json::Value;
 
 use crate::block::Block;
-use crate::field::{Field, TextInput};
+use crate::field::{DatePicker, Field};
 use crate::style::FormStyle;
-use crate::validation::rules::Pattern;
 use crate::validation::ValidationError;
 
 /// A composite block for date ranges (start date and end date).
+///
+/// The block produces a single `DateRangeField` that checks the end date is
+/// on or after the start date. Values are still exported as `prefix_start`
+/// and `prefix_end`.
 pub struct DateRangeBlock {
     prefix: String,
     title: Option<String>,
     required: bool,
+    max_span_days: Option<u32>,
+    allow_open_ended: bool,
 }
 
 impl DateRangeBlock {
@@ -25,6 +30,8 @@ impl DateRangeBlock {
             prefix: prefix.into(),
             title: None,
             required: false,
+            max_span_days: None,
+            allow_open_ended: false,
         }
     }
 
@@ -40,8 +47,16 @@ impl DateRangeBlock {
         self
     }
 
-    fn field_id(&self, name: &str) -> String {
-        format!("{}_{}", self.prefix, name)
+    /// Limits the number of days between the start and end date.
+    pub fn max_span_days(mut self, days: u32) -> Self {
+        self.max_span_days = Some(days);
+        self
+    }
+
+    /// Allows the end date to be left empty, even when the block is required.
+    pub fn allow_open_ended(mut self) -> Self {
+        self.allow_open_ended = true;
+        self
     }
 }
 
@@ -55,56 +70,47 @@ impl Block for DateRangeBlock {
     }
 
     fn fields(&self) -> Vec<Box<dyn Field>> {
-        let mut fields: Vec<Box<dyn Field>> = Vec::new();
-
-        // Start Date
-        let mut start_date = TextInput::new(self.field_id("start"), "Start Date")
-            .placeholder("YYYY-MM-DD")
-            .validator(Box::new(Pattern::date()));
-        if self.required {
-            start_date = start_date.required();
+        let mut field = DateRangeField::new(&self.prefix, self.required);
+        if let Some(days) = self.max_span_days {
+            field = field.max_span_days(days);
         }
-        fields.push(Box::new(start_date));
-
-        // End Date
-        let mut end_date = TextInput::new(self.field_id("end"), "End Date")
-            .placeholder("YYYY-MM-DD")
-            .validator(Box::new(Pattern::date()));
-        if self.required {
-            end_date = end_date.required();
+        if self.allow_open_ended {
+            field = field.allow_open_ended();
         }
-        fields.push(Box::new(end_date));
-
-        fields
+        if let Some(title) = &self.title {
+            field.label = title.clone();
+        }
+        vec![Box::new(field)]
     }
 }
 
 /// A date range field that validates end >= start.
-/// This is used internally to wrap the date range fields with cross-field validation.
-#[allow(dead_code)]
+///
+/// Start and end are separate date pickers; Tab and Up/Down move between them
+/// before leaving the field.
 pub struct DateRangeField {
-    start_field: TextInput,
-    end_field: TextInput,
+    start_field: DatePicker,
+    end_field: DatePicker,
     prefix: String,
+    label: String,
     current_focus: usize, // 0 = start, 1 = end
+    required: bool,
+    max_span_days: Option<u32>,
+    allow_open_ended: bool,
+    validation_errors: Vec<ValidationError>,
 }
 
-#[allow(dead_code)]
 impl DateRangeField {
     /// Creates a new date range field.
     pub fn new(prefix: impl Into<String>, required: bool) -> Self {
         let prefix = prefix.into();
 
-        let mut start_field = TextInput::new(format!("{}_start", prefix), "Start Date")
-            .placeholder("YYYY-MM-DD")
-            .validator(Box::new(Pattern::date()));
+        let mut start_field = DatePicker::new(format!("{}_start", prefix), "Start Date");
         if required {
             start_field = start_field.required();
         }
 
-        let mut end_field = TextInput::new(format!("{}_end", prefix), "End Date")
-            .placeholder("YYYY-MM-DD")
-            .validator(Box::new(Pattern::date()));
+        let mut end_field = DatePicker::new(format!("{}_end", prefix), "End Date");
         if required {
             end_field = end_field.required();
         }
@@ -113,30 +119,47 @@ impl DateRangeField {
             start_field,
             end_field,
             prefix,
+            label: "Date Range".to_string(),
             current_focus: 0,
+            required,
+            max_span_days: None,
+            allow_open_ended: false,
+            validation_errors: Vec::new(),
         }
     }
 
+    /// Limits the number of days between the start and end date.
+    pub fn max_span_days(mut self, days: u32) -> Self {
+        self.max_span_days = Some(days);
+        self
+    }
+
+    /// Allows the end date to be left empty, even when the range is required.
+    pub fn allow_open_ended(mut self) -> Self {
+        self.allow_open_ended = true;
+        self
+    }
+
     fn validate_range(&self) -> Result<(), ValidationError> {
-        let start_value = match self.start_field.value() {
-            Value::String(s) if !s.is_empty() => s,
-            _ => return Ok(()),
+        let (Some(start), Some(end)) = (self.start_field.date(), self.end_field.date()) else {
+            return Ok(());
         };
 
-        let end_value = match self.end_field.value() {
-            Value::String(s) if !s.is_empty() => s,
-            _ => return Ok(()),
+        let message = if end < start {
+            "End date must be on or after start date".to_string()
+        } else if let Some(days) = self
+            .max_span_days
+            .filter(|days| end.to_days() - start.to_days() > i64::from(*days))
+        {
+            format!("Range must not exceed {} days", days)
+        } else {
+            return Ok(());
         };
 
-        // Simple string comparison works for YYYY-MM-DD format
-        if end_value < start_value {
-            Err(ValidationError {
-                field_id: format!("{}_end", self.prefix),
-                message: "End date must be on or after start date".to_string(),
-            })
-        } else {
-            Ok(())
-        }
+        Err(ValidationError {
+            field_id: format!("{}_end", self.prefix),
+            message,
+        })
     }
 }
 
@@ -146,7 +169,7 @@ impl Field for DateRangeField {
     }
 
     fn label(&self) -> &str {
-        "Date Range"
+        &self.label
     }
 
     fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
@@ -154,18 +177,19 @@ impl Field for DateRangeField {
             return;
         }
 
+        let start_height = self.start_field.height().min(area.height - 1);
         let start_area = Rect {
             x: area.x,
             y: area.y,
             width: area.width,
-            height: 1,
+            height: start_height,
         };
 
         let end_area = Rect {
             x: area.x,
-            y: area.y + 1,
+            y: area.y + start_height,
             width: area.width,
-            height: 1,
+            height: area.height - start_height,
         };
 
         self.start_field
@@ -189,6 +213,33 @@ impl Field for DateRangeField {
         })
     }
 
+    fn display_value(&self) -> String {
+        let start = self.start_field.display_value();
+        let end = self.end_field.display_value();
+        match (start.is_empty(), end.is_empty()) {
+            (true, true) => String::new(),
+            (false, true) => format!("from {}", start),
+            (true, false) => format!("until {}", end),
+            (false, false) => format!("{} – {}", start, end),
+        }
+    }
+
+    fn focused_rows(&self, _width: u16) -> Option<(u16, u16)> {
+        let start_height = self.start_field.height();
+        Some(if self.current_focus == 0 {
+            (0, start_height)
+        } else {
+            (start_height, self.end_field.height())
+        })
+    }
+
+    fn entries(&self) -> Vec<(String, Value)> {
+        vec![
+            (self.start_field.id().to_string(), self.start_field.value()),
+            (self.end_field.id().to_string(), self.end_field.value()),
+        ]
+    }
+
     fn validate(&self) -> Result<(), Vec<ValidationError>> {
         let mut errors = Vec::new();
 
@@ -196,8 +247,11 @@ impl Field for DateRangeField {
             errors.append(&mut e);
         }
 
-        if let Err(mut e) = self.end_field.validate() {
-            errors.append(&mut e);
+        // An open-ended range may leave a required end date empty
+        if !(self.allow_open_ended && self.end_field.is_empty()) {
+            if let Err(mut e) = self.end_field.validate() {
+                errors.append(&mut e);
+            }
         }
 
         if let Err(e) = self.validate_range() {
@@ -212,6 +266,68 @@ impl Field for DateRangeField {
     }
 
     fn height(&self) -> u16 {
-        2
+        self.start_field.height() + self.end_field.height()
+    }
+
+    /// Describes both dates at once, since the form draws help below the
+    /// whole range rather than under the focused date.
+    fn help_text(&self) -> Option<&str> {
+        Some(if self.allow_open_ended {
+            "YYYY-MM-DD or Enter for a calendar; the end date may be left empty"
+        } else {
+            "YYYY-MM-DD or Enter for a calendar; the end date is on or after the start"
+        })
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn focus_next(&mut self) -> bool {
+        if self.current_focus == 0 {
+            self.current_focus = 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn focus_previous(&mut self) -> bool {
+        if self.current_focus == 1 {
+            self.current_focus = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn focus_enter(&mut self, backward: bool) {
+        self.current_focus = usize::from(backward);
+    }
+
+    /// Routes each error to the start or end picker. When errors first
+    /// appear, focus moves to the first picker with an error.
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        let (start_errors, end_errors): (Vec<_>, Vec<_>) = errors
+            .iter()
+            .cloned()
+            .partition(|error| error.field_id == self.start_field.id());
+
+        // Re-validation while typing must not pull focus away
+        if self.validation_errors.is_empty() {
+            if !start_errors.is_empty() {
+                self.current_focus = 0;
+            } else if !end_errors.is_empty() {
+                self.current_focus = 1;
+            }
+        }
+
+        self.start_field.set_errors(start_errors);
+        self.end_field.set_errors(end_errors);
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
     }
 }
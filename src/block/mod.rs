@@ -3,10 +3,12 @@
 mod address;
 mod contact;
 mod date_range;
+mod repeat;
 
 pub use address::AddressBlock;
 pub use contact::ContactBlock;
 pub use date_range::DateRangeBlock;
+pub use repeat::RepeatBlock;
 
 use crate::field::Field;
 
@@ -0,0 +1,583 @@
+//! Repeatable block container.
+
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::{Map, Value};
+
+use crate::block::Block;
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// A variable number of copies of a template block ("add another").
+///
+/// The block produces a single `RepeatField`. Ctrl+N adds an item, Ctrl+D
+/// removes the focused item, and Alt+Up / Alt+Down move it. The items are
+/// exported under the block's prefix as an array of objects, with the
+/// template's prefix stripped from each key:
+///
+/// ```text
+/// "members": [{"name": "Ann", "email": "ann@example.com", "phone": ""}]
+/// ```
+///
+/// The help text of the template's fields is not shown: the form draws help
+/// below a whole field, which would put it after the last item rather than
+/// under the field it describes.
+pub struct RepeatBlock {
+    prefix: String,
+    title: Option<String>,
+    item_label: String,
+    template: Arc<dyn Block>,
+    min_items: usize,
+    max_items: Option<usize>,
+    initial_items: usize,
+}
+
+impl RepeatBlock {
+    /// Creates a repeatable block exporting under `prefix`, with one item of
+    /// `template` to start with.
+    pub fn new(prefix: impl Into<String>, template: impl Block + 'static) -> Self {
+        Self {
+            prefix: prefix.into(),
+            title: None,
+            item_label: "Item".to_string(),
+            template: Arc::new(template),
+            min_items: 0,
+            max_items: None,
+            initial_items: 1,
+        }
+    }
+
+    /// Sets the block title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the name shown on each item's header and the add button
+    /// (default "Item").
+    pub fn item_label(mut self, label: impl Into<String>) -> Self {
+        self.item_label = label.into();
+        self
+    }
+
+    /// Requires at least `min` items.
+    pub fn min_items(mut self, min: usize) -> Self {
+        self.min_items = min;
+        self
+    }
+
+    /// Allows at most `max` items.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.max_items = Some(max);
+        self
+    }
+
+    /// Sets how many items the form starts with (default 1).
+    pub fn initial_items(mut self, count: usize) -> Self {
+        self.initial_items = count;
+        self
+    }
+}
+
+impl Block for RepeatBlock {
+    fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    fn fields(&self) -> Vec<Box<dyn Field>> {
+        let mut field = RepeatField {
+            id: self.prefix.clone(),
+            label: self.title.clone().unwrap_or_else(|| self.prefix.clone()),
+            item_label: self.item_label.clone(),
+            template: Arc::clone(&self.template),
+            items: Vec::new(),
+            focus: None,
+            min_items: self.min_items,
+            max_items: self.max_items,
+            validation_errors: Vec::new(),
+        };
+        let initial = self
+            .max_items
+            .map_or(self.initial_items, |max| self.initial_items.min(max));
+        for _ in 0..initial {
+            field.items.push(field.template.fields());
+        }
+        field.focus = field.first_position();
+        vec![Box::new(field)]
+    }
+}
+
+/// A field holding the repeated instances of a template block.
+pub struct RepeatField {
+    id: String,
+    label: String,
+    item_label: String,
+    template: Arc<dyn Block>,
+    items: Vec<Vec<Box<dyn Field>>>,
+    focus: Option<(usize, usize)>, // (item, field); None = the add button
+    min_items: usize,
+    max_items: Option<usize>,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl RepeatField {
+    /// Returns every focus position in order, ending with the add button.
+    fn positions(&self) -> Vec<Option<(usize, usize)>> {
+        let mut positions: Vec<_> = self
+            .items
+            .iter()
+            .enumerate()
+            .flat_map(|(item, fields)| {
+                fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, field)| field.is_focusable())
+                    .map(move |(field, _)| Some((item, field)))
+            })
+            .collect();
+        positions.push(None);
+        positions
+    }
+
+    fn first_position(&self) -> Option<(usize, usize)> {
+        self.positions()[0]
+    }
+
+    fn focused_field(&mut self) -> Option<&mut Box<dyn Field>> {
+        let (item, field) = self.focus?;
+        self.items.get_mut(item)?.get_mut(field)
+    }
+
+    fn can_add(&self) -> bool {
+        self.max_items.is_none_or(|max| self.items.len() < max)
+    }
+
+    /// Inserts a new item after the focused one (or at the end) and focuses it.
+    fn add_item(&mut self) {
+        if !self.can_add() {
+            return;
+        }
+        let index = self.focus.map_or(self.items.len(), |(item, _)| item + 1);
+        self.items.insert(index, self.template.fields());
+        self.focus_item(index, false);
+    }
+
+    /// Removes the focused item and focuses its neighbour.
+    fn remove_item(&mut self) {
+        let Some((item, _)) = self.focus else {
+            return;
+        };
+        self.items.remove(item);
+        if self.items.is_empty() {
+            self.focus = None;
+        } else {
+            self.focus_item(item.min(self.items.len() - 1), false);
+        }
+    }
+
+    /// Swaps the focused item with its neighbour above or below.
+    fn move_item(&mut self, down: bool) {
+        let Some((item, field)) = self.focus else {
+            return;
+        };
+        let target = if down { item + 1 } else { item.wrapping_sub(1) };
+        if target < self.items.len() {
+            self.items.swap(item, target);
+            self.focus = Some((target, field));
+        }
+    }
+
+    /// Focuses the first (or last) field of an item.
+    fn focus_item(&mut self, item: usize, last: bool) {
+        let fields = &self.items[item];
+        let field = if last {
+            fields.iter().rposition(|field| field.is_focusable())
+        } else {
+            fields.iter().position(|field| field.is_focusable())
+        };
+        let Some(field) = field else {
+            self.focus = None;
+            return;
+        };
+        self.focus = Some((item, field));
+        self.items[item][field].focus_enter(last);
+    }
+
+    /// Moves focus one position forward or backward. Returns false at the ends.
+    fn step_focus(&mut self, forward: bool) -> bool {
+        if let Some(field) = self.focused_field() {
+            let moved = if forward {
+                field.focus_next()
+            } else {
+                field.focus_previous()
+            };
+            if moved {
+                return true;
+            }
+        }
+
+        let positions = self.positions();
+        let current = positions
+            .iter()
+            .position(|position| *position == self.focus)
+            .unwrap_or(0);
+        let next = if forward {
+            current + 1
+        } else {
+            match current.checked_sub(1) {
+                Some(next) => next,
+                None => return false,
+            }
+        };
+        let Some(&position) = positions.get(next) else {
+            return false;
+        };
+
+        self.focus = position;
+        if let Some(field) = self.focused_field() {
+            field.focus_enter(!forward);
+        }
+        true
+    }
+
+    /// Returns the item-count errors, shown under the add button.
+    fn count_errors(&self) -> Vec<ValidationError> {
+        self.validation_errors
+            .iter()
+            .filter(|error| error.field_id == self.id)
+            .cloned()
+            .collect()
+    }
+
+    fn item_height(fields: &[Box<dyn Field>], width: u16) -> u16 {
+        1 + fields
+            .iter()
+            .map(|field| field.height_for(width))
+            .sum::<u16>()
+    }
+}
+
+impl Field for RepeatField {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        let bottom = area.y + area.height;
+        let mut y = area.y;
+        let row = |y: u16, height: u16| Rect {
+            x: area.x,
+            y,
+            width: area.width,
+            height: height.min(bottom.saturating_sub(y)),
+        };
+
+        for (item, fields) in self.items.iter().enumerate() {
+            if y >= bottom {
+                return;
+            }
+
+            // Item header
+            let in_item = focused && self.focus.is_some_and(|(i, _)| i == item);
+            let header_style = if in_item {
+                style.label_focused
+            } else {
+                style.label
+            };
+            let header = format!("{} {}", self.item_label, item + 1);
+            Line::from(Span::styled(
+                header,
+                header_style.add_modifier(Modifier::BOLD),
+            ))
+            .render(row(y, 1), buf);
+            y += 1;
+
+            for (index, field) in fields.iter().enumerate() {
+                if y >= bottom {
+                    return;
+                }
+                let is_focused = focused && self.focus == Some((item, index));
+                let height = field.height_for(area.width);
+                field.render(row(y, height), buf, is_focused, style);
+                y += height;
+            }
+        }
+
+        if y >= bottom {
+            return;
+        }
+
+        // Add button with key hints
+        let button = format!("[ + Add {} ]", self.item_label);
+        let button_style = if !self.can_add() {
+            style.placeholder
+        } else if focused && self.focus.is_none() {
+            style.button_focused
+        } else {
+            style.button
+        };
+        Line::from(vec![
+            Span::styled(button, button_style),
+            Span::styled(
+                "  Ctrl+N add · Ctrl+D remove · Alt+↑/↓ move",
+                style.placeholder,
+            ),
+        ])
+        .render(row(y, 1), buf);
+        y += 1;
+
+        if y < bottom {
+            render_error_line(&self.count_errors(), row(y, 1), buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        let ctrl = event.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = event.modifiers.contains(KeyModifiers::ALT);
+
+        match event.code {
+            KeyCode::Char('n') if ctrl => {
+                self.add_item();
+                return true;
+            }
+            KeyCode::Char('d') if ctrl => {
+                self.remove_item();
+                return true;
+            }
+            KeyCode::Up if alt => {
+                self.move_item(false);
+                return true;
+            }
+            KeyCode::Down if alt => {
+                self.move_item(true);
+                return true;
+            }
+            _ => {}
+        }
+
+        let Some(field) = self.focused_field() else {
+            // The add button
+            return match event.code {
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    self.add_item();
+                    true
+                }
+                KeyCode::Up => self.step_focus(false),
+                _ => false,
+            };
+        };
+
+        if field.handle_input(event) {
+            return true;
+        }
+
+        match event.code {
+            KeyCode::Down => self.step_focus(true),
+            KeyCode::Up => self.step_focus(false),
+            _ => false,
+        }
+    }
+
+    fn value(&self) -> Value {
+        let prefix = format!("{}_", self.template.prefix());
+        Value::Array(
+            self.items
+                .iter()
+                .map(|fields| {
+                    let object: Map<String, Value> = fields
+                        .iter()
+                        .flat_map(|field| field.entries())
+                        .map(|(key, value)| match key.strip_prefix(&prefix) {
+                            Some(name) => (name.to_string(), value),
+                            None => (key, value),
+                        })
+                        .collect();
+                    Value::Object(object)
+                })
+                .collect(),
+        )
+    }
+
+    fn display_value(&self) -> String {
+        // One "Member 1: Ann, ann@example.com" entry per item, skipping
+        // items with nothing filled in
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, fields)| {
+                let values: Vec<String> = fields
+                    .iter()
+                    .filter(|field| !field.entries().is_empty())
+                    .map(|field| field.display_value())
+                    .filter(|value| !value.is_empty())
+                    .collect();
+                (!values.is_empty())
+                    .then(|| format!("{} {}: {}", self.item_label, i + 1, values.join(", ")))
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors: Vec<ValidationError> = self
+            .items
+            .iter()
+            .flatten()
+            .filter_map(|field| field.validate().err())
+            .flatten()
+            .collect();
+
+        let count = self.items.len();
+        let message = if count < self.min_items {
+            Some(format!(
+                "Add at least {} {}",
+                self.min_items, self.item_label
+            ))
+        } else {
+            self.max_items
+                .filter(|max| count > *max)
+                .map(|max| format!("At most {} {}", max, self.item_label))
+        };
+        if let Some(message) = message {
+            errors.push(ValidationError {
+                field_id: self.id.clone(),
+                message,
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn height(&self) -> u16 {
+        self.height_for(0)
+    }
+
+    fn height_for(&self, width: u16) -> u16 {
+        let items: u16 = self
+            .items
+            .iter()
+            .map(|fields| Self::item_height(fields, width))
+            .sum();
+        let errors = u16::from(!self.count_errors().is_empty());
+        items + 1 + errors
+    }
+
+    fn is_required(&self) -> bool {
+        self.min_items > 0
+    }
+
+    fn focused_rows(&self, width: u16) -> Option<(u16, u16)> {
+        let mut y = 0;
+        for (item, fields) in self.items.iter().enumerate() {
+            let header = y;
+            y += 1;
+            for (index, field) in fields.iter().enumerate() {
+                if self.focus == Some((item, index)) {
+                    let (top, height) = field
+                        .focused_rows(width)
+                        .unwrap_or((0, field.height_for(width)));
+                    // Keep the item header in view along with its first field
+                    let first = !fields[..index].iter().any(|field| field.is_focusable());
+                    return Some(if first {
+                        (header, y - header + top + height)
+                    } else {
+                        (y + top, height)
+                    });
+                }
+                y += field.height_for(width);
+            }
+        }
+        // The add button
+        Some((y, 1))
+    }
+
+    /// Forwards the form's values to every item field. Each item also sees
+    /// its own fields under their full ids, so a dependent select such as the
+    /// region of [`AddressBlock::countries`](crate::AddressBlock::countries)
+    /// follows the country chosen in the same item.
+    fn update(&mut self, values: &Value) {
+        for fields in &mut self.items {
+            let mut item_values = values.as_object().cloned().unwrap_or_default();
+            for field in fields.iter() {
+                item_values.extend(field.entries());
+            }
+            let item_values = Value::Object(item_values);
+            for field in fields.iter_mut() {
+                field.update(&item_values);
+            }
+        }
+    }
+
+    fn focus_next(&mut self) -> bool {
+        self.step_focus(true)
+    }
+
+    fn focus_previous(&mut self) -> bool {
+        self.step_focus(false)
+    }
+
+    fn focus_enter(&mut self, backward: bool) {
+        if backward {
+            self.focus = None;
+        } else {
+            self.focus = self.first_position();
+            if let Some(field) = self.focused_field() {
+                field.focus_enter(false);
+            }
+        }
+    }
+
+    /// Shows each item field its own errors; the item-count error goes on the
+    /// line under the add button. When errors first appear, focus moves to
+    /// the first invalid field.
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        let first_appearance = self.validation_errors.is_empty();
+        let mut first_invalid = None;
+
+        for (item, fields) in self.items.iter_mut().enumerate() {
+            for (index, field) in fields.iter_mut().enumerate() {
+                let field_errors = if errors.is_empty() {
+                    Vec::new()
+                } else {
+                    field.validate().err().unwrap_or_default()
+                };
+                if !field_errors.is_empty() && first_invalid.is_none() {
+                    first_invalid = Some((item, index));
+                }
+                field.set_errors(field_errors);
+            }
+        }
+
+        if first_appearance && first_invalid.is_some() {
+            self.focus = first_invalid;
+        }
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
@@ -0,0 +1,542 @@
+//! Autocomplete (combobox) text field.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::{ValidationError, Validator};
+
+/// Maximum number of suggestion rows shown in the open dropdown.
+const MAX_VISIBLE_SUGGESTIONS: usize = 8;
+
+/// Provides completions for an [`Autocomplete`] field.
+///
+/// Implemented for `Vec<String>` (case-insensitive matching, prefix matches
+/// first) and for closures `Fn(&str) -> Vec<String>`.
+pub trait Suggest: Send + Sync {
+    /// Returns the completions for the current input, best first.
+    fn suggestions(&self, input: &str) -> Vec<String>;
+}
+
+impl Suggest for Vec<String> {
+    fn suggestions(&self, input: &str) -> Vec<String> {
+        let query = input.to_lowercase();
+        let (mut prefix, substring): (Vec<_>, Vec<_>) = self
+            .iter()
+            .filter(|item| item.to_lowercase().contains(&query))
+            .cloned()
+            .partition(|item| item.to_lowercase().starts_with(&query));
+        prefix.extend(substring);
+        prefix
+    }
+}
+
+impl<F> Suggest for F
+where
+    F: Fn(&str) -> Vec<String> + Send + Sync,
+{
+    fn suggestions(&self, input: &str) -> Vec<String> {
+        self(input)
+    }
+}
+
+/// A text input that offers completions in a dropdown while typing.
+///
+/// Up/Down move through the suggestions and Tab or Enter accepts the
+/// highlighted one. With [`Autocomplete::strict`] the value must be one of the
+/// suggestions for it to validate.
+pub struct Autocomplete {
+    id: String,
+    label: String,
+    help: Option<String>,
+    value: String,
+    cursor_position: usize,
+    placeholder: Option<String>,
+    provider: Box<dyn Suggest>,
+    suggestions: Vec<String>,
+    is_open: bool,
+    highlighted_index: usize,
+    scroll_offset: usize, // first suggestion shown in the dropdown
+    strict: bool,
+    required: bool,
+    validators: Vec<Box<dyn Validator>>,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl Autocomplete {
+    /// Creates a new autocomplete field with no suggestions.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            value: String::new(),
+            cursor_position: 0,
+            placeholder: None,
+            provider: Box::new(Vec::<String>::new()),
+            suggestions: Vec::new(),
+            is_open: false,
+            highlighted_index: 0,
+            scroll_offset: 0,
+            strict: false,
+            required: false,
+            validators: Vec::new(),
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Sets the suggestion provider.
+    pub fn suggestions(mut self, provider: impl Suggest + 'static) -> Self {
+        self.provider = Box::new(provider);
+        self
+    }
+
+    /// Only accepts values that are one of the suggestions.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Sets a placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Adds a validator to this field.
+    pub fn validator(mut self, validator: Box<dyn Validator>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self.cursor_position = self.value.len();
+        self
+    }
+
+    /// Returns the current text.
+    pub(crate) fn text(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the text and moves the cursor to its end.
+    pub(crate) fn set_text(&mut self, text: String) {
+        self.value = text;
+        self.cursor_position = self.value.len();
+    }
+
+    /// Returns whether the suggestion dropdown is open.
+    pub(crate) fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Returns the provider's suggestions for the current text.
+    pub(crate) fn current_suggestions(&self) -> Vec<String> {
+        self.provider.suggestions(&self.value)
+    }
+
+    /// Asks the provider for fresh suggestions and opens the dropdown if
+    /// there is anything to offer beyond the current value.
+    pub(crate) fn refresh(&mut self) {
+        self.suggestions = self.provider.suggestions(&self.value);
+        self.highlighted_index = 0;
+        self.scroll_offset = 0;
+        self.is_open = match self.suggestions.as_slice() {
+            [] => false,
+            [only] => *only != self.value,
+            _ => true,
+        };
+    }
+
+    fn close(&mut self) {
+        self.is_open = false;
+        self.suggestions.clear();
+    }
+
+    /// Replaces the value with the highlighted suggestion.
+    fn complete(&mut self) {
+        if let Some(suggestion) = self.suggestions.get(self.highlighted_index) {
+            self.value = suggestion.clone();
+            self.cursor_position = self.value.len();
+        }
+        self.close();
+    }
+
+    fn move_highlight(&mut self, delta: isize) {
+        let last = self.suggestions.len().saturating_sub(1);
+        self.highlighted_index = self
+            .highlighted_index
+            .saturating_add_signed(delta)
+            .min(last);
+        if self.highlighted_index < self.scroll_offset {
+            self.scroll_offset = self.highlighted_index;
+        } else if self.highlighted_index >= self.scroll_offset + MAX_VISIBLE_SUGGESTIONS {
+            self.scroll_offset = self.highlighted_index + 1 - MAX_VISIBLE_SUGGESTIONS;
+        }
+    }
+
+    fn insert_char(&mut self, c: char) {
+        self.value.insert(self.cursor_position, c);
+        self.cursor_position += c.len_utf8();
+    }
+
+    fn delete_char_before_cursor(&mut self) {
+        if let Some((i, _)) = self.value[..self.cursor_position].char_indices().last() {
+            self.value.remove(i);
+            self.cursor_position = i;
+        }
+    }
+
+    fn delete_char_at_cursor(&mut self) {
+        if self.cursor_position < self.value.len() {
+            self.value.remove(self.cursor_position);
+        }
+    }
+
+    fn move_cursor_left(&mut self) {
+        if let Some((i, _)) = self.value[..self.cursor_position].char_indices().last() {
+            self.cursor_position = i;
+        }
+    }
+
+    fn move_cursor_right(&mut self) {
+        if let Some(c) = self.value[self.cursor_position..].chars().next() {
+            self.cursor_position += c.len_utf8();
+        }
+    }
+
+    fn dropdown_height(&self) -> u16 {
+        self.suggestions.len().min(MAX_VISIBLE_SUGGESTIONS) as u16
+    }
+}
+
+/// Returns the char positions in `text` covered by the first case-insensitive
+/// occurrence of `query`.
+fn matched_positions(text: &str, query: &str) -> Vec<usize> {
+    // Fold each char to a single char so positions stay those of `text`
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let text: Vec<char> = text.chars().map(fold).collect();
+    let query: Vec<char> = query.chars().map(fold).collect();
+    if query.is_empty() || query.len() > text.len() {
+        return Vec::new();
+    }
+    text.windows(query.len())
+        .position(|window| window == query)
+        .map_or_else(Vec::new, |start| (start..start + query.len()).collect())
+}
+
+impl Field for Autocomplete {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate input area
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+
+        if input_width == 0 {
+            return;
+        }
+
+        let input_style = if focused {
+            style.input_focused
+        } else {
+            style.input
+        };
+
+        // Fill input area with background
+        for x in input_x..input_x + input_width {
+            buf[(x, area.y)].set_style(input_style);
+            buf[(x, area.y)].set_char(' ');
+        }
+
+        // Render the value or placeholder
+        let (display_text, display_style) = match self.placeholder {
+            Some(ref placeholder) if self.value.is_empty() => {
+                (placeholder.as_str(), style.placeholder)
+            }
+            _ => (self.value.as_str(), style.input),
+        };
+        buf.set_stringn(
+            input_x,
+            area.y,
+            display_text,
+            input_width as usize,
+            display_style,
+        );
+
+        // Render cursor if focused
+        if focused {
+            let cursor_x = input_x + self.value[..self.cursor_position].width() as u16;
+            if cursor_x < input_x + input_width {
+                buf[(cursor_x, area.y)].set_style(
+                    Style::default()
+                        .bg(Color::White)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::SLOW_BLINK),
+                );
+            }
+        }
+
+        // Render dropdown if open
+        if self.is_open && area.height > 1 {
+            let visible_rows = (area.height - 1).min(self.dropdown_height()) as usize;
+            let first = self.scroll_offset;
+            let last = (first + visible_rows).min(self.suggestions.len());
+
+            for (row, index) in (first..last).enumerate() {
+                let suggestion = &self.suggestions[index];
+                let y = area.y + 1 + row as u16;
+                let row_style = if index == self.highlighted_index {
+                    Style::default().bg(Color::Blue).fg(Color::White)
+                } else {
+                    style.input
+                };
+
+                // Fill suggestion row with background
+                for x in input_x..input_x + input_width {
+                    buf[(x, y)].set_style(row_style);
+                    buf[(x, y)].set_char(' ');
+                }
+
+                // Emphasize the part that matches the input
+                let matched = matched_positions(suggestion, &self.value);
+                for (j, c) in suggestion.chars().enumerate() {
+                    if j as u16 + 2 >= input_width {
+                        break;
+                    }
+                    let cell = &mut buf[(input_x + 2 + j as u16, y)];
+                    cell.set_char(c);
+                    if matched.contains(&j) {
+                        cell.set_style(Modifier::BOLD | Modifier::UNDERLINED);
+                    }
+                }
+            }
+
+            // Indicate suggestions scrolled out of view
+            let indicator_x = (input_x + input_width).saturating_sub(6);
+            let indicator_area = |y| Rect {
+                x: indicator_x,
+                y,
+                width: (input_x + input_width).saturating_sub(indicator_x),
+                height: 1,
+            };
+            if first > 0 {
+                Line::from(Span::styled("↑ more", style.placeholder))
+                    .render(indicator_area(area.y + 1), buf);
+            }
+            if last < self.suggestions.len() && last > first {
+                Line::from(Span::styled("↓ more", style.placeholder))
+                    .render(indicator_area(area.y + (last - first) as u16), buf);
+            }
+        }
+
+        // Render validation errors below the closed dropdown
+        if !self.is_open && area.height > 1 {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + 1,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Tab | KeyCode::Enter if self.is_open => self.complete(),
+            KeyCode::Esc if self.is_open => self.close(),
+            KeyCode::Up if self.is_open => self.move_highlight(-1),
+            KeyCode::Down if self.is_open => self.move_highlight(1),
+            KeyCode::PageUp if self.is_open => {
+                self.move_highlight(-(MAX_VISIBLE_SUGGESTIONS as isize));
+            }
+            KeyCode::PageDown if self.is_open => {
+                self.move_highlight(MAX_VISIBLE_SUGGESTIONS as isize);
+            }
+            // Down on a closed field lists every suggestion for the current text
+            KeyCode::Down => {
+                self.refresh();
+                return self.is_open;
+            }
+            KeyCode::Char(c) if event.modifiers.contains(KeyModifiers::CONTROL) => match c {
+                'a' => self.cursor_position = 0,
+                'e' => self.cursor_position = self.value.len(),
+                'u' => {
+                    self.value.clear();
+                    self.cursor_position = 0;
+                    self.close();
+                }
+                _ => return false,
+            },
+            KeyCode::Char(c) => {
+                self.insert_char(c);
+                self.refresh();
+            }
+            KeyCode::Backspace => {
+                self.delete_char_before_cursor();
+                if self.value.is_empty() {
+                    self.close();
+                } else {
+                    self.refresh();
+                }
+            }
+            KeyCode::Delete => {
+                self.delete_char_at_cursor();
+                self.refresh();
+            }
+            KeyCode::Left => self.move_cursor_left(),
+            KeyCode::Right => self.move_cursor_right(),
+            KeyCode::Home => self.cursor_position = 0,
+            KeyCode::End => self.cursor_position = self.value.len(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn value(&self) -> Value {
+        Value::String(self.value.clone())
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        // Check required
+        if self.required && self.value.trim().is_empty() {
+            errors.push(ValidationError {
+                field_id: self.id.clone(),
+                message: format!("{} is required", self.label),
+            });
+        }
+
+        // Check the value is one of the suggestions
+        if self.strict
+            && !self.value.is_empty()
+            && !self.provider.suggestions(&self.value).contains(&self.value)
+        {
+            errors.push(ValidationError {
+                field_id: self.id.clone(),
+                message: "Choose one of the suggestions".to_string(),
+            });
+        }
+
+        // Run validators
+        for validator in &self.validators {
+            if let Err(msg) = validator.validate(&self.value) {
+                errors.push(ValidationError {
+                    field_id: self.id.clone(),
+                    message: msg,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn height(&self) -> u16 {
+        if self.is_open {
+            1 + self.dropdown_height()
+        } else if self.validation_errors.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matched_positions;
+
+    #[test]
+    fn matches_case_insensitively() {
+        assert_eq!(matched_positions("Berlin", "ERL"), vec![1, 2, 3]);
+        assert_eq!(matched_positions("Berlin", "x"), Vec::<usize>::new());
+        assert_eq!(matched_positions("Berlin", ""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn positions_follow_the_original_chars() {
+        // 'İ' lowercases to two chars, which must not shift the match
+        assert_eq!(matched_positions("İstanbul", "stan"), vec![1, 2, 3, 4]);
+        assert_eq!(matched_positions("İzmir", "i"), vec![0]);
+        assert_eq!(matched_positions("Ärztekammer", "ärz"), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn query_longer_than_text_does_not_match() {
+        assert_eq!(matched_positions("İ", "ii"), Vec::<usize>::new());
+    }
+}
@@ -8,7 +8,7 @@ use ratatui::widgets::Widget;
 use serde_json::Value;
 use unicode_width::UnicodeWidthStr;
 
-use crate::field::Field;
+use crate::field::{render_error_line, Field};
 use crate::style::FormStyle;
 use crate::validation::ValidationError;
 
@@ -16,8 +16,10 @@ use crate::validation::ValidationError;
 pub struct Checkbox {
     id: String,
     label: String,
+    help: Option<String>,
     checked: bool,
     required: bool,
+    validation_errors: Vec<ValidationError>,
 }
 
 impl Checkbox {
@@ -26,8 +28,10 @@ impl Checkbox {
         Self {
             id: id.into(),
             label: label.into(),
+            help: None,
             checked: false,
             required: false,
+            validation_errors: Vec::new(),
         }
     }
 
@@ -37,6 +41,12 @@ impl Checkbox {
         self
     }
 
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
     /// Marks this field as required (must be checked).
     pub fn required(mut self) -> Self {
         self.required = true;
@@ -100,6 +110,17 @@ impl Field for Checkbox {
             };
             label_line.render(label_area, buf);
         }
+
+        // Render validation errors aligned with the label
+        if area.height > 1 && remaining_width > 1 {
+            let error_area = Rect {
+                x: label_x + 1,
+                y: area.y + 1,
+                width: remaining_width - 1,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
     }
 
     fn handle_input(&mut self, event: &KeyEvent) -> bool {
@@ -128,10 +149,26 @@ impl Field for Checkbox {
     }
 
     fn height(&self) -> u16 {
-        1
+        if self.validation_errors.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
     }
 
     fn is_required(&self) -> bool {
         self.required
     }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
 }
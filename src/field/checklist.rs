@@ -0,0 +1,269 @@
+//! Multi-select checklist field.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// A list of options where any number can be checked.
+///
+/// Up/Down move the highlight and Space/Enter toggles it. The value is
+/// exported as a JSON array of the checked option values.
+pub struct CheckList {
+    id: String,
+    label: String,
+    help: Option<String>,
+    options: Vec<(String, String)>, // (value, display)
+    checked: Vec<bool>,
+    highlighted_index: usize,
+    min_selected: Option<usize>,
+    max_selected: Option<usize>,
+    required: bool,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl CheckList {
+    /// Creates a new checklist field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            options: Vec::new(),
+            checked: Vec::new(),
+            highlighted_index: 0,
+            min_selected: None,
+            max_selected: None,
+            required: false,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Adds an option to the list.
+    pub fn option(mut self, value: impl Into<String>, display: impl Into<String>) -> Self {
+        self.options.push((value.into(), display.into()));
+        self.checked.push(false);
+        self
+    }
+
+    /// Adds multiple options at once.
+    pub fn options(mut self, options: Vec<(impl Into<String>, impl Into<String>)>) -> Self {
+        for (value, display) in options {
+            self = self.option(value, display);
+        }
+        self
+    }
+
+    /// Requires at least `min` options to be checked.
+    pub fn min_selected(mut self, min: usize) -> Self {
+        self.min_selected = Some(min);
+        self
+    }
+
+    /// Allows at most `max` options to be checked.
+    pub fn max_selected(mut self, max: usize) -> Self {
+        self.max_selected = Some(max);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required (at least one option must be checked).
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the initially checked values.
+    pub fn initial_values(mut self, values: &[&str]) -> Self {
+        for (i, (value, _)) in self.options.iter().enumerate() {
+            self.checked[i] = values.contains(&value.as_str());
+        }
+        self
+    }
+
+    fn selected_count(&self) -> usize {
+        self.checked.iter().filter(|checked| **checked).count()
+    }
+
+    fn options_height(&self) -> u16 {
+        self.options.len().max(1) as u16
+    }
+}
+
+impl Field for CheckList {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate options area
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+
+        if input_width == 0 {
+            return;
+        }
+
+        let options_height = self.options_height().min(area.height);
+        for (i, (_, display)) in self.options.iter().enumerate() {
+            if i as u16 >= options_height {
+                break;
+            }
+
+            let marker = if self.checked[i] { "[✓]" } else { "[ ]" };
+            let text = format!("{} {}", marker, display);
+            let option_style = if focused && i == self.highlighted_index {
+                style.input_focused
+            } else {
+                style.label
+            };
+
+            let option_area = Rect {
+                x: input_x,
+                y: area.y + i as u16,
+                width: input_width.min(text.width() as u16),
+                height: 1,
+            };
+            Line::from(Span::styled(text.as_str(), option_style)).render(option_area, buf);
+        }
+
+        // Render validation errors below the options
+        if area.height > options_height {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + options_height,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if let Some(checked) = self.checked.get_mut(self.highlighted_index) {
+                    *checked = !*checked;
+                }
+                true
+            }
+            // Moving past the first/last option hands focus back to the form
+            KeyCode::Up if self.highlighted_index > 0 => {
+                self.highlighted_index -= 1;
+                true
+            }
+            KeyCode::Down if self.highlighted_index + 1 < self.options.len() => {
+                self.highlighted_index += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::Array(
+            self.options
+                .iter()
+                .zip(&self.checked)
+                .filter(|(_, checked)| **checked)
+                .map(|((value, _), _)| Value::String(value.clone()))
+                .collect(),
+        )
+    }
+
+    fn display_value(&self) -> String {
+        self.options
+            .iter()
+            .zip(&self.checked)
+            .filter(|(_, checked)| **checked)
+            .map(|((_, display), _)| display.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let count = self.selected_count();
+        let message = if self.required && count == 0 {
+            Some(format!("{} is required", self.label))
+        } else if let Some(min) = self.min_selected.filter(|min| count < *min) {
+            Some(format!("Select at least {}", min))
+        } else {
+            self.max_selected
+                .filter(|max| count > *max)
+                .map(|max| format!("Select at most {}", max))
+        };
+
+        match message {
+            Some(message) => Err(vec![ValidationError {
+                field_id: self.id.clone(),
+                message,
+            }]),
+            None => Ok(()),
+        }
+    }
+
+    fn height(&self) -> u16 {
+        if self.validation_errors.is_empty() {
+            self.options_height()
+        } else {
+            self.options_height() + 1
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
@@ -0,0 +1,227 @@
+//! Yes/No confirmation field.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// An explicit Yes/No question that starts unanswered.
+///
+/// Left/Right move between the choices and Enter or Space picks one; `y` and
+/// `n` answer directly and Backspace clears the answer. The value is exported
+/// as a bool, or `null` until a choice is made, so [`Confirm::required`] can
+/// tell "not answered" apart from "no".
+pub struct Confirm {
+    id: String,
+    label: String,
+    help: Option<String>,
+    answer: Option<bool>,
+    highlighted_yes: bool,
+    yes_label: String,
+    no_label: String,
+    required: bool,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl Confirm {
+    /// Creates a new, unanswered confirm field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            answer: None,
+            highlighted_yes: true,
+            yes_label: "Yes".to_string(),
+            no_label: "No".to_string(),
+            required: false,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Sets the initial answer.
+    pub fn initial_value(mut self, answer: bool) -> Self {
+        self.answer = Some(answer);
+        self.highlighted_yes = answer;
+        self
+    }
+
+    /// Sets the text of the two choices (default "Yes" / "No").
+    pub fn labels(mut self, yes: impl Into<String>, no: impl Into<String>) -> Self {
+        self.yes_label = yes.into();
+        self.no_label = no.into();
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required (must be answered, either way).
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    fn choose(&mut self, answer: bool) {
+        self.answer = Some(answer);
+        self.highlighted_yes = answer;
+    }
+
+    fn choice_text(&self, yes: bool) -> String {
+        let marker = if self.answer == Some(yes) {
+            "(•)"
+        } else {
+            "( )"
+        };
+        let text = if yes { &self.yes_label } else { &self.no_label };
+        format!("{} {}", marker, text)
+    }
+}
+
+impl Field for Confirm {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate choices area
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+
+        if input_width == 0 {
+            return;
+        }
+
+        let choice_style = |yes: bool| {
+            if focused && yes == self.highlighted_yes {
+                style.input_focused
+            } else {
+                style.label
+            }
+        };
+        let line = Line::from(vec![
+            Span::styled(self.choice_text(true), choice_style(true)),
+            Span::raw("  "),
+            Span::styled(self.choice_text(false), choice_style(false)),
+        ]);
+        line.render(
+            Rect {
+                x: input_x,
+                y: area.y,
+                width: input_width,
+                height: 1,
+            },
+            buf,
+        );
+
+        // Render validation errors below the choices
+        if area.height > 1 {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + 1,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Enter | KeyCode::Char(' ') => self.choose(self.highlighted_yes),
+            KeyCode::Char('y') | KeyCode::Char('Y') => self.choose(true),
+            KeyCode::Char('n') | KeyCode::Char('N') => self.choose(false),
+            KeyCode::Left => self.highlighted_yes = true,
+            KeyCode::Right => self.highlighted_yes = false,
+            KeyCode::Backspace | KeyCode::Delete => self.answer = None,
+            _ => return false,
+        }
+        true
+    }
+
+    fn value(&self) -> Value {
+        self.answer.map_or(Value::Null, Value::Bool)
+    }
+
+    fn display_value(&self) -> String {
+        match self.answer {
+            Some(true) => self.yes_label.clone(),
+            Some(false) => self.no_label.clone(),
+            None => String::new(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        if self.required && self.answer.is_none() {
+            Err(vec![ValidationError {
+                field_id: self.id.clone(),
+                message: format!("{} is required", self.label),
+            }])
+        } else {
+            Ok(())
+        }
+    }
+
+    fn height(&self) -> u16 {
+        if self.validation_errors.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
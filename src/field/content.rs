@@ -0,0 +1,221 @@
+//! Display-only content: headings, paragraphs and dividers.
+
+use crossterm::event::KeyEvent;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::Field;
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// A section heading between fields.
+///
+/// Headings are never focused and contribute nothing to the form's JSON
+/// output. Blocks with a title get one automatically when added with
+/// [`FormBuilder::block`](crate::FormBuilder::block).
+pub struct Heading {
+    text: String,
+}
+
+impl Heading {
+    /// Creates a new heading.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+impl Field for Heading {
+    fn id(&self) -> &str {
+        ""
+    }
+
+    fn label(&self) -> &str {
+        &self.text
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, _focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        let heading_style = style.title.add_modifier(Modifier::BOLD);
+        Line::from(Span::styled(self.text.as_str(), heading_style)).render(area, buf);
+    }
+
+    fn handle_input(&mut self, _event: &KeyEvent) -> bool {
+        false
+    }
+
+    fn value(&self) -> Value {
+        Value::Null
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        Ok(())
+    }
+
+    fn entries(&self) -> Vec<(String, Value)> {
+        Vec::new()
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+}
+
+/// A block of explanatory text, word-wrapped to the form's width.
+///
+/// Paragraphs are never focused and contribute nothing to the form's JSON
+/// output.
+pub struct Paragraph {
+    text: String,
+}
+
+impl Paragraph {
+    /// Creates a new paragraph.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+impl Field for Paragraph {
+    fn id(&self) -> &str {
+        ""
+    }
+
+    fn label(&self) -> &str {
+        &self.text
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, _focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        for (row, line) in wrap_text(&self.text, area.width as usize)
+            .iter()
+            .take(area.height as usize)
+            .enumerate()
+        {
+            buf.set_stringn(
+                area.x,
+                area.y + row as u16,
+                line,
+                area.width as usize,
+                style.label,
+            );
+        }
+    }
+
+    fn handle_input(&mut self, _event: &KeyEvent) -> bool {
+        false
+    }
+
+    fn value(&self) -> Value {
+        Value::Null
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        Ok(())
+    }
+
+    fn entries(&self) -> Vec<(String, Value)> {
+        Vec::new()
+    }
+
+    fn height(&self) -> u16 {
+        self.height_for(0)
+    }
+
+    fn height_for(&self, width: u16) -> u16 {
+        wrap_text(&self.text, width as usize).len().max(1) as u16
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+}
+
+/// A horizontal rule between fields.
+///
+/// Dividers are never focused and contribute nothing to the form's JSON
+/// output.
+#[derive(Default)]
+pub struct Divider;
+
+impl Divider {
+    /// Creates a new divider.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Field for Divider {
+    fn id(&self) -> &str {
+        ""
+    }
+
+    fn label(&self) -> &str {
+        ""
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, _focused: bool, style: &FormStyle) {
+        if area.height < 1 {
+            return;
+        }
+
+        for x in area.left()..area.right() {
+            buf[(x, area.y)].set_char('─').set_style(style.border);
+        }
+    }
+
+    fn handle_input(&mut self, _event: &KeyEvent) -> bool {
+        false
+    }
+
+    fn value(&self) -> Value {
+        Value::Null
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        Ok(())
+    }
+
+    fn entries(&self) -> Vec<(String, Value)> {
+        Vec::new()
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+}
+
+/// Splits `text` into lines no wider than `width`, breaking at spaces where
+/// possible. A width of 0 only breaks at newlines.
+pub(crate) fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let needed = if line.is_empty() {
+                word.width()
+            } else {
+                line.width() + 1 + word.width()
+            };
+            if width > 0 && needed > width && !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
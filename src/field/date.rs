@@ -0,0 +1,652 @@
+//! Calendar date picker field.
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Width of the month grid: seven two-column days separated by spaces.
+const CALENDAR_WIDTH: u16 = 20;
+
+/// Height of the month grid: month header, weekday header and six weeks.
+const CALENDAR_HEIGHT: u16 = 8;
+
+/// A proleptic Gregorian calendar date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct CalendarDate {
+    pub(crate) year: i32,
+    pub(crate) month: u32,
+    pub(crate) day: u32,
+}
+
+impl CalendarDate {
+    /// Creates a date, returning `None` if it does not exist on the calendar.
+    pub(crate) fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Parses a `YYYY-MM-DD` string.
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.split('-');
+        let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() || year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return None;
+        }
+        if !s.bytes().all(|b| b.is_ascii_digit() || b == b'-') {
+            return None;
+        }
+        Self::new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// Returns today's date in UTC.
+    pub(crate) fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Converts days since 1970-01-01 to a date.
+    fn from_days(days: i64) -> Self {
+        // Howard Hinnant's civil_from_days algorithm
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// Converts the date to days since 1970-01-01.
+    pub(crate) fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(self.day)
+            - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Returns the date `days` days later (or earlier, if negative).
+    pub(crate) fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Returns the same day `months` months later, clamped to the month's length.
+    pub(crate) fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+        let day = self.day.min(days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// Returns the day of the week, with Monday as 0.
+    fn weekday(self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as u32
+    }
+
+    fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns whether `year` is a leap year.
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in `month` (1-12) of `year`.
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// A date field with a month-grid popup.
+///
+/// Dates can be typed directly as `YYYY-MM-DD`, or picked from the calendar
+/// opened with Enter/Space: arrows move by day and week, PageUp/PageDown by
+/// month, and Enter selects. The value is exported as a `YYYY-MM-DD` string.
+pub struct DatePicker {
+    id: String,
+    label: String,
+    help: Option<String>,
+    text: String,
+    cursor_position: usize,
+    is_open: bool,
+    highlighted: CalendarDate,
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+    required: bool,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl DatePicker {
+    /// Creates a new date picker field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            text: String::new(),
+            cursor_position: 0,
+            is_open: false,
+            highlighted: CalendarDate::today(),
+            min: None,
+            max: None,
+            required: false,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Sets the earliest selectable date (`YYYY-MM-DD`).
+    ///
+    /// # Panics
+    /// Panics if `date` is not a valid `YYYY-MM-DD` date.
+    pub fn min_date(mut self, date: &str) -> Self {
+        self.min = Some(CalendarDate::parse(date).expect("Invalid min date"));
+        self
+    }
+
+    /// Sets the latest selectable date (`YYYY-MM-DD`).
+    ///
+    /// # Panics
+    /// Panics if `date` is not a valid `YYYY-MM-DD` date.
+    pub fn max_date(mut self, date: &str) -> Self {
+        self.max = Some(CalendarDate::parse(date).expect("Invalid max date"));
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the initial value (`YYYY-MM-DD`).
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self.cursor_position = self.text.len();
+        self
+    }
+
+    /// Returns the entered date, if it is a valid calendar date.
+    pub(crate) fn date(&self) -> Option<CalendarDate> {
+        CalendarDate::parse(&self.text)
+    }
+
+    /// Returns whether nothing has been entered.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    fn clamp(&self, date: CalendarDate) -> CalendarDate {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    fn in_range(&self, date: CalendarDate) -> bool {
+        self.clamp(date) == date
+    }
+
+    fn open(&mut self) {
+        self.is_open = true;
+        self.highlighted = self.clamp(self.date().unwrap_or_else(CalendarDate::today));
+    }
+
+    fn select_highlighted(&mut self) {
+        self.text = self.highlighted.to_string();
+        self.cursor_position = self.text.len();
+        self.is_open = false;
+    }
+
+    fn move_highlight(&mut self, days: i64, months: i32) {
+        let date = self.highlighted.add_months(months).add_days(days);
+        self.highlighted = self.clamp(date);
+    }
+
+    fn handle_calendar_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Enter | KeyCode::Char(' ') => self.select_highlighted(),
+            KeyCode::Esc => self.is_open = false,
+            KeyCode::Left => self.move_highlight(-1, 0),
+            KeyCode::Right => self.move_highlight(1, 0),
+            KeyCode::Up => self.move_highlight(-7, 0),
+            KeyCode::Down => self.move_highlight(7, 0),
+            KeyCode::PageUp => self.move_highlight(0, -1),
+            KeyCode::PageDown => self.move_highlight(0, 1),
+            KeyCode::Home => {
+                self.highlighted = self.clamp(self.highlighted.first_of_month());
+            }
+            KeyCode::End => {
+                let last = days_in_month(self.highlighted.year, self.highlighted.month);
+                self.highlighted = self.clamp(CalendarDate {
+                    day: last,
+                    ..self.highlighted
+                });
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn render_calendar(&self, x: u16, y: u16, buf: &mut Buffer, style: &FormStyle) {
+        let month = self.highlighted.first_of_month();
+        let selected = self.date();
+
+        // Fill popup with background
+        for row in y..y + CALENDAR_HEIGHT {
+            for col in x..x + CALENDAR_WIDTH {
+                buf[(col, row)].set_style(style.input);
+                buf[(col, row)].set_char(' ');
+            }
+        }
+
+        let header = format!(
+            "‹ {} {} ›",
+            MONTH_NAMES[month.month as usize - 1],
+            month.year
+        );
+        let header_x = x + CALENDAR_WIDTH.saturating_sub(header.width() as u16) / 2;
+        buf.set_string(
+            header_x,
+            y,
+            &header,
+            style.input.add_modifier(Modifier::BOLD),
+        );
+        buf.set_string(x, y + 1, "Mo Tu We Th Fr Sa Su", style.input);
+
+        let offset = month.weekday();
+        let days = days_in_month(month.year, month.month);
+        for day in 1..=days {
+            let slot = offset + day - 1;
+            let col = x + (slot % 7) as u16 * 3;
+            let row = y + 2 + (slot / 7) as u16;
+            let date = CalendarDate { day, ..month };
+
+            let day_style = if date == self.highlighted {
+                Style::default().bg(Color::Blue).fg(Color::White)
+            } else if Some(date) == selected {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else if !self.in_range(date) {
+                style.input.patch(style.placeholder)
+            } else {
+                style.input
+            };
+            buf.set_string(col, row, format!("{:>2}", day), day_style);
+        }
+    }
+}
+
+impl Field for DatePicker {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate input area
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+
+        if input_width < 3 {
+            return;
+        }
+
+        let input_style = if focused {
+            style.input_focused
+        } else {
+            style.input
+        };
+
+        // Fill input area with background
+        for x in input_x..input_x + input_width {
+            buf[(x, area.y)].set_style(input_style);
+            buf[(x, area.y)].set_char(' ');
+        }
+
+        // Render the typed date or a format hint
+        let (display_text, display_style) = if self.text.is_empty() {
+            ("YYYY-MM-DD", style.placeholder)
+        } else {
+            (self.text.as_str(), style.input)
+        };
+        let visible: String = display_text
+            .chars()
+            .take(input_width as usize - 2)
+            .collect();
+        buf.set_string(input_x, area.y, &visible, display_style);
+
+        // Render arrow
+        let arrow = if self.is_open { "▲" } else { "▼" };
+        buf.set_string(input_x + input_width - 1, area.y, arrow, input_style);
+
+        // Render cursor if focused
+        if focused && !self.is_open {
+            let cursor_x = input_x + self.cursor_position as u16;
+            if cursor_x < input_x + input_width - 2 {
+                buf[(cursor_x, area.y)].set_style(
+                    Style::default()
+                        .bg(Color::White)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::SLOW_BLINK),
+                );
+            }
+        }
+
+        // Render the calendar popup if open
+        if self.is_open && area.height > CALENDAR_HEIGHT && input_width >= CALENDAR_WIDTH {
+            self.render_calendar(input_x, area.y + 1, buf, style);
+        }
+
+        // Render validation errors below the closed field
+        if !self.is_open && area.height > 1 {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + 1,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        if self.is_open {
+            return self.handle_calendar_input(event);
+        }
+
+        match event.code {
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                self.open();
+                true
+            }
+            KeyCode::Char('u') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.text.clear();
+                self.cursor_position = 0;
+                true
+            }
+            KeyCode::Char(c)
+                if (c.is_ascii_digit() || c == '-')
+                    && !event.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                if self.text.len() < 10 {
+                    self.text.insert(self.cursor_position, c);
+                    self.cursor_position += 1;
+                }
+                true
+            }
+            KeyCode::Backspace => {
+                if self.cursor_position > 0 {
+                    self.cursor_position -= 1;
+                    self.text.remove(self.cursor_position);
+                }
+                true
+            }
+            KeyCode::Delete => {
+                if self.cursor_position < self.text.len() {
+                    self.text.remove(self.cursor_position);
+                }
+                true
+            }
+            KeyCode::Left => {
+                self.cursor_position = self.cursor_position.saturating_sub(1);
+                true
+            }
+            KeyCode::Right => {
+                self.cursor_position = (self.cursor_position + 1).min(self.text.len());
+                true
+            }
+            KeyCode::Home => {
+                self.cursor_position = 0;
+                true
+            }
+            KeyCode::End => {
+                self.cursor_position = self.text.len();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn value(&self) -> Value {
+        if self.text.is_empty() {
+            Value::Null
+        } else {
+            Value::String(self.text.clone())
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let error = |message: String| {
+            Err(vec![ValidationError {
+                field_id: self.id.clone(),
+                message,
+            }])
+        };
+
+        if self.text.is_empty() {
+            if self.required {
+                return error(format!("{} is required", self.label));
+            }
+            return Ok(());
+        }
+
+        let Some(date) = self.date() else {
+            return error("Invalid date (use YYYY-MM-DD)".to_string());
+        };
+        if let Some(min) = self.min.filter(|min| date < *min) {
+            return error(format!("Must be on or after {}", min));
+        }
+        if let Some(max) = self.max.filter(|max| date > *max) {
+            return error(format!("Must be on or before {}", max));
+        }
+
+        Ok(())
+    }
+
+    fn height(&self) -> u16 {
+        if self.is_open {
+            1 + CALENDAR_HEIGHT
+        } else if self.validation_errors.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    fn press(picker: &mut DatePicker, code: KeyCode) {
+        picker.handle_input(&KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(2023));
+        assert!(!is_leap_year(1900));
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+    }
+
+    #[test]
+    fn february_29_exists_only_in_leap_years() {
+        assert!(CalendarDate::new(2024, 2, 29).is_some());
+        assert!(CalendarDate::new(2023, 2, 29).is_none());
+        assert!(CalendarDate::new(1900, 2, 29).is_none());
+        assert_eq!(CalendarDate::parse("2000-02-29"), Some(date(2000, 2, 29)));
+        assert_eq!(CalendarDate::parse("2023-02-29"), None);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_dates() {
+        assert_eq!(CalendarDate::parse("2024-1-05"), None);
+        assert_eq!(CalendarDate::parse("2024-13-01"), None);
+        assert_eq!(CalendarDate::parse("2024-04-31"), None);
+        assert_eq!(CalendarDate::parse("+024-01-01"), None);
+        assert_eq!(CalendarDate::parse("2024-01-01-01"), None);
+    }
+
+    #[test]
+    fn day_numbers_match_the_calendar() {
+        assert_eq!(date(1970, 1, 1).to_days(), 0);
+        assert_eq!(date(1969, 12, 31).to_days(), -1);
+        assert_eq!(date(2000, 3, 1).to_days(), 11_017);
+        assert_eq!(CalendarDate::from_days(19_782), date(2024, 2, 29));
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 2, 28).add_days(1), date(2023, 3, 1));
+        // 2024-01-01 was a Monday
+        assert_eq!(date(2024, 1, 1).weekday(), 0);
+    }
+
+    #[test]
+    fn day_numbers_round_trip() {
+        for days in (-800_000..800_000).step_by(997) {
+            assert_eq!(CalendarDate::from_days(days).to_days(), days);
+        }
+    }
+
+    #[test]
+    fn stepping_months_clamps_to_the_month_end() {
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 3, 31).add_months(-1), date(2024, 2, 29));
+        assert_eq!(date(2024, 2, 29).add_months(12), date(2025, 2, 28));
+        assert_eq!(date(2024, 12, 15).add_months(1), date(2025, 1, 15));
+        assert_eq!(date(2024, 1, 10).add_months(-1), date(2023, 12, 10));
+    }
+
+    #[test]
+    fn calendar_page_down_clamps_to_the_month_end() {
+        let mut picker = DatePicker::new("d", "Date").initial_value("2024-01-31");
+        press(&mut picker, KeyCode::Enter);
+        press(&mut picker, KeyCode::PageDown);
+        press(&mut picker, KeyCode::Enter);
+        assert_eq!(picker.value(), Value::String("2024-02-29".to_string()));
+    }
+
+    #[test]
+    fn calendar_stays_within_min_and_max() {
+        let mut picker = DatePicker::new("d", "Date")
+            .min_date("2024-02-10")
+            .max_date("2024-02-20")
+            .initial_value("2024-02-12");
+        press(&mut picker, KeyCode::Enter);
+        press(&mut picker, KeyCode::Up);
+        press(&mut picker, KeyCode::Enter);
+        assert_eq!(picker.value(), Value::String("2024-02-10".to_string()));
+
+        press(&mut picker, KeyCode::Enter);
+        press(&mut picker, KeyCode::PageDown);
+        press(&mut picker, KeyCode::Enter);
+        assert_eq!(picker.value(), Value::String("2024-02-20".to_string()));
+    }
+}
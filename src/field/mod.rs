@@ -1,16 +1,45 @@
 //! Field types for form inputs.
 
+mod autocomplete;
 mod checkbox;
+mod checklist;
+mod confirm;
+mod content;
+mod date;
+mod number;
+mod path;
+mod radio;
 mod select;
+mod slider;
+mod tag;
 mod text;
+mod textarea;
+mod time;
+mod toggle;
 
+pub use autocomplete::{Autocomplete, Suggest};
 pub use checkbox::Checkbox;
-pub use select::Select;
+pub use checklist::CheckList;
+pub use confirm::Confirm;
+pub(crate) use content::wrap_text;
+pub use content::{Divider, Heading, Paragraph};
+pub use date::DatePicker;
+pub use number::Number;
+pub use path::PathInput;
+pub use radio::RadioGroup;
+pub use select::{OptionsProvider, Select};
+pub use slider::Slider;
+pub use tag::TagInput;
 pub use text::TextInput;
+pub use textarea::TextArea;
+pub use time::{DateTimeInput, TimeInput};
+pub use toggle::Toggle;
 
 use crossterm::event::KeyEvent;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
 use serde_json::Value;
 
 use crate::style::FormStyle;
@@ -36,13 +65,131 @@ pub trait Field: Send + Sync {
     /// Validates the field and returns any errors.
     fn validate(&self) -> Result<(), Vec<ValidationError>>;
 
+    /// Returns the value as text for people to read, e.g. on the review
+    /// screen. Defaults to a readable form of [`Field::value`]; fields with
+    /// options return the option's display text instead of its value.
+    fn display_value(&self) -> String {
+        display_json(&self.value())
+    }
+
+    /// Returns the `(key, value)` pairs this field contributes to the form's
+    /// JSON output. Defaults to a single entry keyed by [`Field::id`].
+    fn entries(&self) -> Vec<(String, Value)> {
+        vec![(self.id().to_string(), self.value())]
+    }
+
     /// Returns the height needed to render this field.
     fn height(&self) -> u16 {
         1
     }
 
+    /// Returns the height needed to render this field `width` columns wide.
+    /// The form lays fields out with this; override it for content that wraps.
+    /// Defaults to [`Field::height`].
+    fn height_for(&self, _width: u16) -> u16 {
+        self.height()
+    }
+
     /// Returns whether this field is required.
     fn is_required(&self) -> bool {
         false
     }
+
+    /// Returns a description of the field, shown below it while it is focused.
+    fn help_text(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns whether the field can take focus. Display-only content such as
+    /// [`Heading`] returns false and is skipped by keyboard navigation.
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    /// Moves focus to the next part of a composite field. Returns true if
+    /// focus moved within the field, false to let the form move on.
+    fn focus_next(&mut self) -> bool {
+        false
+    }
+
+    /// Moves focus to the previous part of a composite field. Returns true if
+    /// focus moved within the field, false to let the form move on.
+    fn focus_previous(&mut self) -> bool {
+        false
+    }
+
+    /// Called by the form after every key press with the current values of
+    /// all fields, keyed like [`Form::to_json`](crate::Form::to_json), so a
+    /// field can depend on others. Does nothing by default.
+    fn update(&mut self, _values: &Value) {}
+
+    /// Returns the rows `(top, height)` of the focused part of a composite
+    /// field laid out `width` columns wide, relative to the field's top, so a
+    /// scrolling form can keep that part visible. Defaults to `None`, meaning
+    /// the whole field.
+    fn focused_rows(&self, _width: u16) -> Option<(u16, u16)> {
+        None
+    }
+
+    /// Called when the field gains focus; `backward` is true when focus
+    /// arrived from the field after it, so composites can focus their last part.
+    fn focus_enter(&mut self, _backward: bool) {}
+
+    /// Returns whether the value is secret (e.g. a password) and should be
+    /// redacted by [`Form::to_json_redacted`](crate::Form::to_json_redacted).
+    fn is_sensitive(&self) -> bool {
+        false
+    }
+
+    /// Stores validation errors for the field to display. Called by the form
+    /// after validation; an empty vector clears previously shown errors.
+    fn set_errors(&mut self, _errors: Vec<ValidationError>) {}
+
+    /// Returns the validation errors currently shown by this field.
+    fn errors(&self) -> &[ValidationError] {
+        &[]
+    }
+
+    /// Clears any validation errors shown by this field.
+    fn clear_errors(&mut self) {
+        self.set_errors(Vec::new());
+    }
+}
+
+/// Formats a JSON value for display: strings without quotes, booleans as
+/// Yes/No, arrays as comma-separated lists, and `null` as an empty string.
+fn display_json(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::Bool(true) => "Yes".to_string(),
+        Value::Bool(false) => "No".to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_json)
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key, display_json(value)))
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+/// Renders the first error message on the first row of `area`.
+///
+/// Fields call this from [`Field::render`] to show the errors passed to
+/// [`Field::set_errors`], usually on a row below the input.
+pub fn render_error_line(
+    errors: &[ValidationError],
+    area: Rect,
+    buf: &mut Buffer,
+    style: &FormStyle,
+) {
+    if let Some(error) = errors.first() {
+        let error_area = Rect { height: 1, ..area };
+        Line::from(Span::styled(error.message.as_str(), style.error)).render(error_area, buf);
+    }
 }
@@ -0,0 +1,384 @@
+//! Numeric input field.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// A numeric input field.
+///
+/// Accepts integers by default; call [`Number::decimal`] to allow a fractional
+/// part. Only numeric keystrokes are accepted. Up/Down and `+`/`-` step the
+/// value, and the value is exported as a JSON number.
+pub struct Number {
+    id: String,
+    label: String,
+    help: Option<String>,
+    text: String,
+    cursor_position: usize,
+    placeholder: Option<String>,
+    decimal: bool,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: f64,
+    required: bool,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl Number {
+    /// Creates a new integer input field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            text: String::new(),
+            cursor_position: 0,
+            placeholder: None,
+            decimal: false,
+            min: None,
+            max: None,
+            step: 1.0,
+            required: false,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Allows decimal values instead of only integers.
+    pub fn decimal(mut self) -> Self {
+        self.decimal = true;
+        self
+    }
+
+    /// Sets the minimum allowed value.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum allowed value.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount added or removed by the increment keys (default 1).
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Sets a placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: f64) -> Self {
+        self.text = self.format(value);
+        self.cursor_position = self.text.len();
+        self
+    }
+
+    /// Parses the entered text, returning `None` if it is empty or invalid.
+    fn parsed(&self) -> Option<f64> {
+        if self.decimal {
+            self.text.parse::<f64>().ok()
+        } else {
+            self.text.parse::<i64>().ok().map(|n| n as f64)
+        }
+    }
+
+    /// Formats a value with as many decimals as the step or current text use.
+    fn format(&self, value: f64) -> String {
+        if !self.decimal {
+            return format!("{}", value.round() as i64);
+        }
+
+        let decimals = |s: &str| s.split_once('.').map_or(0, |(_, frac)| frac.len());
+        let precision = decimals(&self.step.to_string()).max(decimals(&self.text));
+        format!("{:.*}", precision, value)
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+
+    fn step_by(&mut self, direction: f64) {
+        let current = self
+            .parsed()
+            .unwrap_or_else(|| self.clamp(0.0) - direction * self.step);
+        let next = self.clamp(current + direction * self.step);
+        self.text = self.format(next);
+        self.cursor_position = self.text.len();
+    }
+
+    fn accepts_minus(&self) -> bool {
+        self.cursor_position == 0
+            && !self.text.starts_with('-')
+            && self.min.is_none_or(|min| min < 0.0)
+    }
+
+    fn insert_char(&mut self, c: char) {
+        self.text.insert(self.cursor_position, c);
+        self.cursor_position += c.len_utf8();
+    }
+
+    fn delete_char_before_cursor(&mut self) {
+        if self.cursor_position > 0 {
+            self.cursor_position -= 1;
+            self.text.remove(self.cursor_position);
+        }
+    }
+
+    fn delete_char_at_cursor(&mut self) {
+        if self.cursor_position < self.text.len() {
+            self.text.remove(self.cursor_position);
+        }
+    }
+}
+
+impl Field for Number {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate input area
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+
+        if input_width == 0 {
+            return;
+        }
+
+        let (display_text, display_style) = if self.text.is_empty() {
+            match self.placeholder {
+                Some(ref placeholder) => (placeholder.as_str(), style.placeholder),
+                None => ("", style.input),
+            }
+        } else {
+            (self.text.as_str(), style.input)
+        };
+
+        let input_bg_style = if focused {
+            style.input_focused
+        } else {
+            style.input
+        };
+
+        // Fill input area with background
+        for x in input_x..input_x + input_width {
+            buf[(x, area.y)].set_style(input_bg_style);
+            buf[(x, area.y)].set_char(' ');
+        }
+
+        // Render the text
+        for (i, c) in display_text.chars().take(input_width as usize).enumerate() {
+            buf[(input_x + i as u16, area.y)].set_char(c);
+            buf[(input_x + i as u16, area.y)].set_style(display_style);
+        }
+
+        // Render cursor if focused
+        if focused {
+            let cursor_x = input_x + self.cursor_position as u16;
+            if cursor_x < area.x + area.width {
+                buf[(cursor_x, area.y)].set_style(
+                    Style::default()
+                        .bg(Color::White)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::SLOW_BLINK),
+                );
+            }
+        }
+
+        // Render validation errors if any
+        if area.height > 1 {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + 1,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Char('u') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.text.clear();
+                self.cursor_position = 0;
+                true
+            }
+            KeyCode::Char(_) if event.modifiers.contains(KeyModifiers::CONTROL) => false,
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                self.insert_char(c);
+                true
+            }
+            KeyCode::Char('.') if self.decimal && !self.text.contains('.') => {
+                self.insert_char('.');
+                true
+            }
+            KeyCode::Char('-') if self.accepts_minus() => {
+                self.insert_char('-');
+                true
+            }
+            KeyCode::Up | KeyCode::Char('+') => {
+                self.step_by(1.0);
+                true
+            }
+            KeyCode::Down | KeyCode::Char('-') => {
+                self.step_by(-1.0);
+                true
+            }
+            KeyCode::Backspace => {
+                self.delete_char_before_cursor();
+                true
+            }
+            KeyCode::Delete => {
+                self.delete_char_at_cursor();
+                true
+            }
+            KeyCode::Left => {
+                self.cursor_position = self.cursor_position.saturating_sub(1);
+                true
+            }
+            KeyCode::Right => {
+                self.cursor_position = (self.cursor_position + 1).min(self.text.len());
+                true
+            }
+            KeyCode::Home => {
+                self.cursor_position = 0;
+                true
+            }
+            KeyCode::End => {
+                self.cursor_position = self.text.len();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn value(&self) -> Value {
+        if self.decimal {
+            self.parsed()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .unwrap_or(Value::Null)
+        } else {
+            self.text
+                .parse::<i64>()
+                .map(Value::from)
+                .unwrap_or(Value::Null)
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let error = |message: String| {
+            Err(vec![ValidationError {
+                field_id: self.id.clone(),
+                message,
+            }])
+        };
+
+        if self.text.is_empty() {
+            if self.required {
+                return error(format!("{} is required", self.label));
+            }
+            return Ok(());
+        }
+
+        let Some(value) = self.parsed() else {
+            return if self.decimal {
+                error("Must be a number".to_string())
+            } else {
+                error("Must be a whole number".to_string())
+            };
+        };
+
+        if let Some(min) = self.min.filter(|min| value < *min) {
+            return error(format!("Must be at least {}", self.format(min)));
+        }
+        if let Some(max) = self.max.filter(|max| value > *max) {
+            return error(format!("Must be at most {}", self.format(max)));
+        }
+
+        Ok(())
+    }
+
+    fn height(&self) -> u16 {
+        if self.validation_errors.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
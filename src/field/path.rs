@@ -0,0 +1,235 @@
+//! Filesystem path field with completion.
+
+use std::fs;
+use std::path::MAIN_SEPARATOR;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use serde_json::Value;
+
+use crate::field::{Autocomplete, Field, Suggest};
+use crate::style::FormStyle;
+use crate::validation::rules::{expand_home, Extension};
+use crate::validation::{ValidationError, Validator};
+
+/// Lists directory entries matching the last segment of a typed path.
+struct PathSuggest {
+    directories_only: bool,
+    extensions: Option<Extension>,
+}
+
+impl Suggest for PathSuggest {
+    fn suggestions(&self, input: &str) -> Vec<String> {
+        // Split into the directory typed so far and the partial entry name
+        let (dir, partial) = match input.rfind(['/', MAIN_SEPARATOR]) {
+            Some(i) => input.split_at(i + 1),
+            None => ("", input),
+        };
+        let read_from = if dir.is_empty() {
+            expand_home(".")
+        } else {
+            expand_home(dir)
+        };
+
+        let Ok(entries) = fs::read_dir(read_from) else {
+            return Vec::new();
+        };
+
+        let mut matches: Vec<(bool, String)> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                // Hidden entries only show once a leading dot is typed
+                if !name.starts_with(partial)
+                    || (name.starts_with('.') && !partial.starts_with('.'))
+                {
+                    return None;
+                }
+                let is_dir = entry.path().is_dir();
+                if !is_dir
+                    && (self.directories_only
+                        || self
+                            .extensions
+                            .as_ref()
+                            .is_some_and(|extensions| !extensions.matches(&entry.path())))
+                {
+                    return None;
+                }
+                let suffix = if is_dir { "/" } else { "" };
+                Some((is_dir, format!("{}{}{}", dir, name, suffix)))
+            })
+            .collect();
+
+        // Directories first, then files, each alphabetically
+        matches.sort_by(|(a_dir, a), (b_dir, b)| b_dir.cmp(a_dir).then_with(|| a.cmp(b)));
+        matches.into_iter().map(|(_, path)| path).collect()
+    }
+}
+
+/// Returns the longest common prefix of `items`.
+fn common_prefix(items: &[String]) -> &str {
+    let Some(first) = items.first() else {
+        return "";
+    };
+    let mut len = first.len();
+    for item in &items[1..] {
+        len = first
+            .char_indices()
+            .zip(item.chars())
+            .take_while(|((_, a), b)| a == b)
+            .last()
+            .map_or(0, |((i, c), _)| i + c.len_utf8())
+            .min(len);
+    }
+    &first[..len]
+}
+
+/// A text input for filesystem paths with completion from the local disk.
+///
+/// Typing lists the matching entries of the current directory in a dropdown.
+/// Tab completes the longest common prefix, or accepts the highlighted entry
+/// when the dropdown is open; accepting a directory lists its contents next.
+/// `~/` is expanded to the home directory when listing entries. The value is
+/// exported as the path string as typed.
+pub struct PathInput {
+    input: Autocomplete,
+    directories_only: bool,
+    extensions: Vec<String>,
+}
+
+impl PathInput {
+    /// Creates a new path field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            input: Autocomplete::new(id, label),
+            directories_only: false,
+            extensions: Vec::new(),
+        }
+        .with_provider()
+    }
+
+    /// Only suggests directories.
+    pub fn directories_only(mut self) -> Self {
+        self.directories_only = true;
+        self.with_provider()
+    }
+
+    /// Only suggests files with one of the given extensions (directories are
+    /// still listed so they can be browsed).
+    pub fn extensions(mut self, extensions: &[&str]) -> Self {
+        self.extensions = extensions.iter().map(|ext| ext.to_string()).collect();
+        self.with_provider()
+    }
+
+    /// Sets a placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.input = self.input.placeholder(placeholder);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.input = self.input.help(help);
+        self
+    }
+
+    /// Marks this field as required.
+    pub fn required(mut self) -> Self {
+        self.input = self.input.required();
+        self
+    }
+
+    /// Adds a validator, e.g. [`MustExist`](crate::validation::rules::MustExist)
+    /// or [`IsDir`](crate::validation::rules::IsDir).
+    pub fn validator(mut self, validator: Box<dyn Validator>) -> Self {
+        self.input = self.input.validator(validator);
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.input = self.input.initial_value(value);
+        self
+    }
+
+    fn with_provider(mut self) -> Self {
+        let extensions: Vec<&str> = self.extensions.iter().map(String::as_str).collect();
+        self.input = self.input.suggestions(PathSuggest {
+            directories_only: self.directories_only,
+            extensions: (!extensions.is_empty()).then(|| Extension::new(&extensions)),
+        });
+        self
+    }
+
+    /// Extends the text to the longest prefix shared by all matching entries.
+    /// Returns false when there is nothing to add, so Tab moves focus on.
+    fn complete_common_prefix(&mut self) -> bool {
+        let suggestions = self.input.current_suggestions();
+        let prefix = common_prefix(&suggestions);
+        if prefix.len() <= self.input.text().len() {
+            return false;
+        }
+
+        self.input.set_text(prefix.to_string());
+        self.input.refresh();
+        true
+    }
+}
+
+impl Field for PathInput {
+    fn id(&self) -> &str {
+        self.input.id()
+    }
+
+    fn label(&self) -> &str {
+        self.input.label()
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        self.input.render(area, buf, focused, style);
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Tab if !self.input.is_open() => self.complete_common_prefix(),
+            KeyCode::Tab | KeyCode::Enter if self.input.is_open() => {
+                self.input.handle_input(event);
+                // Keep browsing into an accepted directory
+                if self.input.text().ends_with(['/', MAIN_SEPARATOR]) {
+                    self.input.refresh();
+                }
+                true
+            }
+            _ => self.input.handle_input(event),
+        }
+    }
+
+    fn value(&self) -> Value {
+        self.input.value()
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        self.input.validate()
+    }
+
+    fn height(&self) -> u16 {
+        self.input.height()
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.input.help_text()
+    }
+
+    fn is_required(&self) -> bool {
+        self.input.is_required()
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.input.set_errors(errors);
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        self.input.errors()
+    }
+}
@@ -0,0 +1,276 @@
+//! Radio button group field.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// A group of mutually exclusive options that are all visible at once.
+///
+/// Options are stacked vertically by default; see [`RadioGroup::horizontal`].
+/// The arrow keys move the highlight and Space/Enter selects it.
+pub struct RadioGroup {
+    id: String,
+    label: String,
+    help: Option<String>,
+    options: Vec<(String, String)>, // (value, display)
+    selected_index: Option<usize>,
+    highlighted_index: usize,
+    horizontal: bool,
+    required: bool,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl RadioGroup {
+    /// Creates a new radio group.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            options: Vec::new(),
+            selected_index: None,
+            highlighted_index: 0,
+            horizontal: false,
+            required: false,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Adds an option to the group.
+    pub fn option(mut self, value: impl Into<String>, display: impl Into<String>) -> Self {
+        self.options.push((value.into(), display.into()));
+        self
+    }
+
+    /// Adds multiple options at once.
+    pub fn options(mut self, options: Vec<(impl Into<String>, impl Into<String>)>) -> Self {
+        for (value, display) in options {
+            self.options.push((value.into(), display.into()));
+        }
+        self
+    }
+
+    /// Lays the options out on a single row, navigated with Left/Right.
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the initial selected value.
+    pub fn initial_value(mut self, value: &str) -> Self {
+        if let Some(i) = self.options.iter().position(|(v, _)| v == value) {
+            self.selected_index = Some(i);
+            self.highlighted_index = i;
+        }
+        self
+    }
+
+    fn move_highlight(&mut self, forward: bool) -> bool {
+        if forward && self.highlighted_index + 1 < self.options.len() {
+            self.highlighted_index += 1;
+            true
+        } else if !forward && self.highlighted_index > 0 {
+            self.highlighted_index -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn option_text(&self, index: usize) -> String {
+        let marker = if Some(index) == self.selected_index {
+            "(•)"
+        } else {
+            "( )"
+        };
+        format!("{} {}", marker, self.options[index].1)
+    }
+
+    fn options_height(&self) -> u16 {
+        if self.horizontal {
+            1
+        } else {
+            self.options.len().max(1) as u16
+        }
+    }
+}
+
+impl Field for RadioGroup {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate options area
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+
+        if input_width == 0 {
+            return;
+        }
+
+        let options_height = self.options_height().min(area.height);
+        let mut x = input_x;
+        for i in 0..self.options.len() {
+            let text = self.option_text(i);
+            let option_style = if focused && i == self.highlighted_index {
+                style.input_focused
+            } else {
+                style.label
+            };
+
+            let (option_x, y) = if self.horizontal {
+                (x, area.y)
+            } else if (i as u16) < options_height {
+                (input_x, area.y + i as u16)
+            } else {
+                break;
+            };
+
+            let available = (input_x + input_width).saturating_sub(option_x);
+            if available == 0 {
+                break;
+            }
+            let option_area = Rect {
+                x: option_x,
+                y,
+                width: available.min(text.width() as u16),
+                height: 1,
+            };
+            Line::from(Span::styled(text.as_str(), option_style)).render(option_area, buf);
+            x = option_x + text.width() as u16 + 2;
+        }
+
+        // Render validation errors below the options
+        if area.height > options_height {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + options_height,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if self.highlighted_index < self.options.len() {
+                    self.selected_index = Some(self.highlighted_index);
+                }
+                true
+            }
+            // Moving past the first/last option hands focus back to the form
+            KeyCode::Up if !self.horizontal => self.move_highlight(false),
+            KeyCode::Down if !self.horizontal => self.move_highlight(true),
+            KeyCode::Left if self.horizontal => {
+                self.move_highlight(false);
+                true
+            }
+            KeyCode::Right if self.horizontal => {
+                self.move_highlight(true);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn value(&self) -> Value {
+        self.selected_index
+            .and_then(|i| self.options.get(i))
+            .map(|(v, _)| Value::String(v.clone()))
+            .unwrap_or(Value::Null)
+    }
+
+    fn display_value(&self) -> String {
+        self.selected_index
+            .and_then(|i| self.options.get(i))
+            .map(|(_, display)| display.clone())
+            .unwrap_or_default()
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        if self.required && self.selected_index.is_none() {
+            Err(vec![ValidationError {
+                field_id: self.id.clone(),
+                message: format!("{} is required", self.label),
+            }])
+        } else {
+            Ok(())
+        }
+    }
+
+    fn height(&self) -> u16 {
+        if self.validation_errors.is_empty() {
+            self.options_height()
+        } else {
+            self.options_height() + 1
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
@@ -1,27 +1,65 @@
 //! Select/dropdown field.
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Widget;
 use serde_json::Value;
 use unicode_width::UnicodeWidthStr;
 
-use crate::field::Field;
+use crate::field::{render_error_line, Field};
 use crate::style::FormStyle;
 use crate::validation::ValidationError;
 
+/// Maximum number of option rows shown in the open dropdown.
+const MAX_VISIBLE_OPTIONS: usize = 10;
+
+/// Computes a [`Select`]'s options from the form's current values, for lists
+/// that depend on another field (e.g. regions of the chosen country).
+///
+/// Implemented for closures `Fn(&Value) -> Vec<(String, String)>` returning
+/// `(value, display)` pairs.
+pub trait OptionsProvider: Send + Sync {
+    /// Returns the options for the given form values, keyed like
+    /// [`Form::to_json`](crate::Form::to_json).
+    fn options(&self, values: &Value) -> Vec<(String, String)>;
+}
+
+impl<F> OptionsProvider for F
+where
+    F: Fn(&Value) -> Vec<(String, String)> + Send + Sync,
+{
+    fn options(&self, values: &Value) -> Vec<(String, String)> {
+        self(values)
+    }
+}
+
 /// A select/dropdown field.
+///
+/// While the dropdown is open, typed characters filter the options by
+/// substring or fuzzy match on their display text. Use
+/// [`Select::filterable`] to turn this off for short lists.
+///
+/// The dropdown shows a scrolling window of at most ten options around the
+/// highlight, so rendering cost does not grow with the number of options.
 pub struct Select {
     id: String,
     label: String,
+    help: Option<String>,
     options: Vec<(String, String)>, // (value, display)
+    provider: Option<Box<dyn OptionsProvider>>,
+    pending_value: Option<String>, // initial value not yet among the options
     selected_index: Option<usize>,
     is_open: bool,
-    highlighted_index: usize,
+    highlighted_index: usize, // position within the filtered options
+    scroll_offset: usize,     // first filtered position shown in the dropdown
+    filterable: bool,
+    filter: String,
+    filtered: Option<Vec<usize>>, // option indices matching `filter`, best first; None = all
     required: bool,
+    validation_errors: Vec<ValidationError>,
 }
 
 impl Select {
@@ -30,11 +68,19 @@ impl Select {
         Self {
             id: id.into(),
             label: label.into(),
+            help: None,
             options: Vec::new(),
+            provider: None,
+            pending_value: None,
             selected_index: None,
             is_open: false,
             highlighted_index: 0,
+            scroll_offset: 0,
+            filterable: true,
+            filter: String::new(),
+            filtered: None,
             required: false,
+            validation_errors: Vec::new(),
         }
     }
 
@@ -52,51 +98,176 @@ impl Select {
         self
     }
 
+    /// Computes the options from the form's current values, replacing any
+    /// static options. They are recomputed after every key press; when the
+    /// selected value is no longer offered, the selection is cleared.
+    ///
+    /// A required select whose provider returns no options is not enforced,
+    /// so a country without regions can still be submitted.
+    pub fn options_from(mut self, provider: impl OptionsProvider + 'static) -> Self {
+        self.provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
     /// Marks this field as required.
     pub fn required(mut self) -> Self {
         self.required = true;
         self
     }
 
-    /// Sets the initial selected value.
+    /// Enables or disables type-to-filter (enabled by default).
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Sets the initial selected value. With [`Select::options_from`], it is
+    /// selected once the provider first offers it.
     pub fn initial_value(mut self, value: &str) -> Self {
-        for (i, (v, _)) in self.options.iter().enumerate() {
-            if v == value {
+        match self.options.iter().position(|(v, _)| v == value) {
+            Some(i) => {
                 self.selected_index = Some(i);
                 self.highlighted_index = i;
-                break;
             }
+            None => self.pending_value = Some(value.to_string()),
         }
         self
     }
 
     fn toggle_open(&mut self) {
         self.is_open = !self.is_open;
+        self.filter.clear();
+        self.apply_filter();
         if self.is_open {
             if let Some(idx) = self.selected_index {
                 self.highlighted_index = idx;
             }
+            self.scroll_to_highlight();
         }
     }
 
     fn select_highlighted(&mut self) {
-        if !self.options.is_empty() {
-            self.selected_index = Some(self.highlighted_index);
+        if let Some(index) = self.filtered_option(self.highlighted_index) {
+            self.selected_index = Some(index);
+            self.pending_value = None;
         }
         self.is_open = false;
+        self.filter.clear();
+    }
+
+    /// Returns the number of options matching the filter.
+    fn filtered_len(&self) -> usize {
+        self.filtered
+            .as_ref()
+            .map_or(self.options.len(), |filtered| filtered.len())
+    }
+
+    /// Returns the option index at `position` among the filtered options.
+    fn filtered_option(&self, position: usize) -> Option<usize> {
+        match self.filtered {
+            Some(ref filtered) => filtered.get(position).copied(),
+            None => (position < self.options.len()).then_some(position),
+        }
+    }
+
+    /// Moves the highlight by `delta` positions, clamped to the filtered options.
+    fn move_highlight(&mut self, delta: isize) {
+        let last = self.filtered_len().saturating_sub(1);
+        self.highlighted_index = self
+            .highlighted_index
+            .saturating_add_signed(delta)
+            .min(last);
+        self.scroll_to_highlight();
+    }
+
+    /// Adjusts the scroll window so the highlighted option is visible.
+    fn scroll_to_highlight(&mut self) {
+        if self.highlighted_index < self.scroll_offset {
+            self.scroll_offset = self.highlighted_index;
+        } else if self.highlighted_index >= self.scroll_offset + MAX_VISIBLE_OPTIONS {
+            self.scroll_offset = self.highlighted_index + 1 - MAX_VISIBLE_OPTIONS;
+        }
+    }
+
+    /// Recomputes the options matching the filter: substring matches first,
+    /// then fuzzy (in-order subsequence) matches.
+    fn apply_filter(&mut self) {
+        self.highlighted_index = 0;
+        self.scroll_offset = 0;
+        if self.filter.is_empty() {
+            self.filtered = None;
+            return;
+        }
+
+        let query: Vec<char> = self.filter.chars().map(fold_case).collect();
+        let mut substring = Vec::new();
+        let mut fuzzy = Vec::new();
+        for (i, (_, display)) in self.options.iter().enumerate() {
+            match find_match(display, &query) {
+                Some(Match::Substring(_)) => substring.push(i),
+                Some(Match::Fuzzy(_)) => fuzzy.push(i),
+                None => {}
+            }
+        }
+        substring.append(&mut fuzzy);
+        self.filtered = Some(substring);
     }
 
-    fn move_highlight_up(&mut self) {
-        if self.highlighted_index > 0 {
-            self.highlighted_index -= 1;
+    fn dropdown_height(&self) -> u16 {
+        // An empty result still takes a row for the "No matches" message
+        self.filtered_len().clamp(1, MAX_VISIBLE_OPTIONS) as u16
+    }
+}
+
+/// How an option's display text matched the filter query.
+enum Match {
+    /// The query appears contiguously; holds the matched char positions.
+    Substring(Vec<usize>),
+    /// The query chars appear in order; holds the matched char positions.
+    Fuzzy(Vec<usize>),
+}
+
+impl Match {
+    fn positions(&self) -> &[usize] {
+        match self {
+            Match::Substring(positions) | Match::Fuzzy(positions) => positions,
         }
     }
+}
+
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn find_match(text: &str, query: &[char]) -> Option<Match> {
+    let text: Vec<char> = text.chars().map(fold_case).collect();
+    if query.is_empty() || query.len() > text.len() {
+        return None;
+    }
 
-    fn move_highlight_down(&mut self) {
-        if self.highlighted_index < self.options.len().saturating_sub(1) {
-            self.highlighted_index += 1;
+    if let Some(start) = text.windows(query.len()).position(|window| window == query) {
+        return Some(Match::Substring((start..start + query.len()).collect()));
+    }
+
+    let mut positions = Vec::with_capacity(query.len());
+    let mut remaining = query.iter().peekable();
+    for (i, c) in text.iter().enumerate() {
+        if remaining.peek() == Some(&c) {
+            positions.push(i);
+            remaining.next();
         }
     }
+    if remaining.peek().is_none() {
+        Some(Match::Fuzzy(positions))
+    } else {
+        None
+    }
 }
 
 impl Field for Select {
@@ -142,12 +313,16 @@ impl Field for Select {
             return;
         }
 
-        // Get selected display text
-        let display_text = self
-            .selected_index
-            .and_then(|i| self.options.get(i))
-            .map(|(_, display)| display.as_str())
-            .unwrap_or("-- Select --");
+        // Show the filter query while typing, otherwise the selected display text
+        let filtering = self.is_open && !self.filter.is_empty();
+        let display_text = if filtering {
+            self.filter.as_str()
+        } else {
+            self.selected_index
+                .and_then(|i| self.options.get(i))
+                .map(|(_, display)| display.as_str())
+                .unwrap_or("-- Select --")
+        };
 
         // Render the selected value with dropdown indicator
         let input_style = if focused {
@@ -174,6 +349,19 @@ impl Field for Select {
             buf[(input_x + i as u16, area.y)].set_char(c);
         }
 
+        // Render a cursor after the filter query
+        if filtering && focused {
+            let cursor_x = input_x + truncated_text.width() as u16;
+            if cursor_x < input_x + input_width.saturating_sub(2) {
+                buf[(cursor_x, area.y)].set_style(
+                    Style::default()
+                        .bg(Color::White)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::SLOW_BLINK),
+                );
+            }
+        }
+
         // Render arrow
         let arrow_x = input_x + input_width - 2;
         for (i, c) in arrow.chars().enumerate() {
@@ -184,16 +372,36 @@ impl Field for Select {
 
         // Render dropdown if open
         if self.is_open && area.height > 1 {
-            let max_dropdown_height = (area.height - 1).min(self.options.len() as u16);
+            let visible_rows = (area.height - 1).min(self.dropdown_height()) as usize;
+            let total = self.filtered_len();
+
+            if total == 0 {
+                let empty_area = Rect {
+                    x: input_x + 2,
+                    y: area.y + 1,
+                    width: input_width.saturating_sub(2),
+                    height: 1,
+                };
+                Line::from(Span::styled("No matches", style.placeholder)).render(empty_area, buf);
+            }
+
+            // Only the rows in the scroll window are drawn
+            let mut first = self.scroll_offset;
+            if self.highlighted_index >= first + visible_rows {
+                first = self.highlighted_index + 1 - visible_rows;
+            }
+            let last = (first + visible_rows).min(total);
 
-            for (i, (_, display)) in self.options.iter().enumerate() {
-                if i >= max_dropdown_height as usize {
+            let query: Vec<char> = self.filter.chars().map(fold_case).collect();
+            for (row, position) in (first..last).enumerate() {
+                let Some(option_index) = self.filtered_option(position) else {
                     break;
-                }
+                };
 
-                let y = area.y + 1 + i as u16;
-                let is_highlighted = i == self.highlighted_index;
-                let is_selected = Some(i) == self.selected_index;
+                let (_, display) = &self.options[option_index];
+                let y = area.y + 1 + row as u16;
+                let is_highlighted = position == self.highlighted_index;
+                let is_selected = Some(option_index) == self.selected_index;
 
                 let option_style = if is_highlighted {
                     Style::default().bg(Color::Blue).fg(Color::White)
@@ -215,19 +423,71 @@ impl Field for Select {
                     buf[(input_x + j as u16, y)].set_char(c);
                 }
 
+                // Emphasize the characters that matched the filter
+                let matched = find_match(display, &query);
+                let matched = matched.as_ref().map_or(&[][..], Match::positions);
                 let text_start = input_x + 2;
                 for (j, c) in display.chars().enumerate() {
                     if text_start + j as u16 >= input_x + input_width {
                         break;
                     }
-                    buf[(text_start + j as u16, y)].set_char(c);
+                    let cell = &mut buf[(text_start + j as u16, y)];
+                    cell.set_char(c);
+                    if matched.contains(&j) {
+                        cell.set_style(Modifier::BOLD | Modifier::UNDERLINED);
+                    }
                 }
             }
+
+            // Indicate options scrolled out of view
+            let indicator_x = (input_x + input_width).saturating_sub(6);
+            let indicator_area = |y| Rect {
+                x: indicator_x,
+                y,
+                width: (input_x + input_width).saturating_sub(indicator_x),
+                height: 1,
+            };
+            if first > 0 {
+                Line::from(Span::styled("↑ more", style.placeholder))
+                    .render(indicator_area(area.y + 1), buf);
+            }
+            if last < total && last > first {
+                Line::from(Span::styled("↓ more", style.placeholder))
+                    .render(indicator_area(area.y + (last - first) as u16), buf);
+            }
+        }
+
+        // Render validation errors below the closed dropdown
+        if !self.is_open && area.height > 1 {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + 1,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
         }
     }
 
     fn handle_input(&mut self, event: &KeyEvent) -> bool {
         match event.code {
+            KeyCode::Char(c)
+                if self.filterable
+                    && !event.modifiers.contains(KeyModifiers::CONTROL)
+                    && (c != ' ' || (self.is_open && !self.filter.is_empty())) =>
+            {
+                if !self.is_open {
+                    self.toggle_open();
+                }
+                self.filter.push(c);
+                self.apply_filter();
+                true
+            }
+            KeyCode::Backspace if self.is_open && self.filterable => {
+                self.filter.pop();
+                self.apply_filter();
+                true
+            }
             KeyCode::Enter | KeyCode::Char(' ') => {
                 if self.is_open {
                     self.select_highlighted();
@@ -237,22 +497,43 @@ impl Field for Select {
                 true
             }
             KeyCode::Esc if self.is_open => {
-                self.is_open = false;
+                if self.filter.is_empty() {
+                    self.is_open = false;
+                } else {
+                    self.filter.clear();
+                    self.apply_filter();
+                }
                 true
             }
             KeyCode::Up if self.is_open => {
-                self.move_highlight_up();
+                self.move_highlight(-1);
                 true
             }
             KeyCode::Down => {
                 if self.is_open {
-                    self.move_highlight_down();
+                    self.move_highlight(1);
                     true
                 } else {
                     self.toggle_open();
                     true
                 }
             }
+            KeyCode::PageUp if self.is_open => {
+                self.move_highlight(-(MAX_VISIBLE_OPTIONS as isize));
+                true
+            }
+            KeyCode::PageDown if self.is_open => {
+                self.move_highlight(MAX_VISIBLE_OPTIONS as isize);
+                true
+            }
+            KeyCode::Home if self.is_open => {
+                self.move_highlight(isize::MIN);
+                true
+            }
+            KeyCode::End if self.is_open => {
+                self.move_highlight(isize::MAX);
+                true
+            }
             _ => false,
         }
     }
@@ -264,8 +545,16 @@ impl Field for Select {
             .unwrap_or(Value::Null)
     }
 
+    fn display_value(&self) -> String {
+        self.selected_index
+            .and_then(|i| self.options.get(i))
+            .map(|(_, display)| display.clone())
+            .unwrap_or_default()
+    }
+
     fn validate(&self) -> Result<(), Vec<ValidationError>> {
-        if self.required && self.selected_index.is_none() {
+        let nothing_to_choose = self.provider.is_some() && self.options.is_empty();
+        if self.required && self.selected_index.is_none() && !nothing_to_choose {
             Err(vec![ValidationError {
                 field_id: self.id.clone(),
                 message: format!("{} is required", self.label),
@@ -277,13 +566,55 @@ impl Field for Select {
 
     fn height(&self) -> u16 {
         if self.is_open {
-            1 + self.options.len().min(10) as u16
-        } else {
+            1 + self.dropdown_height()
+        } else if self.validation_errors.is_empty() {
             1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn update(&mut self, values: &Value) {
+        let Some(ref provider) = self.provider else {
+            return;
+        };
+        let options = provider.options(values);
+        if options == self.options {
+            return;
+        }
+
+        // Keep the selection if it is still offered, otherwise clear it
+        let wanted = self
+            .selected_index
+            .and_then(|i| self.options.get(i))
+            .map(|(v, _)| v.clone())
+            .or_else(|| self.pending_value.clone());
+        self.options = options;
+        self.selected_index =
+            wanted.and_then(|wanted| self.options.iter().position(|(v, _)| *v == wanted));
+        if self.selected_index.is_some() {
+            self.pending_value = None;
         }
+
+        self.is_open = false;
+        self.filter.clear();
+        self.apply_filter();
+        self.highlighted_index = self.selected_index.unwrap_or(0);
     }
 
     fn is_required(&self) -> bool {
         self.required
     }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
 }
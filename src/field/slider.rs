@@ -0,0 +1,320 @@
+//! Slider field for bounded numeric values.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::Field;
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// A horizontal slider for a value between a minimum and a maximum.
+///
+/// Left/Right move the thumb by one step, PageUp/PageDown by a larger page
+/// step, and Home/End jump to the ends. When ticks are added with
+/// [`Slider::tick`] the slider moves between the ticks instead, and their
+/// labels are shown under the track. The value is exported as a JSON number.
+pub struct Slider {
+    id: String,
+    label: String,
+    help: Option<String>,
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    page_step: Option<f64>,
+    ticks: Vec<(f64, String)>,
+}
+
+impl Slider {
+    /// Creates a new slider from 0 to 100 in steps of 1.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            value: 0.0,
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            page_step: None,
+            ticks: Vec::new(),
+        }
+    }
+
+    /// Sets the minimum value (default 0).
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self.value = self.clamp(self.value);
+        self
+    }
+
+    /// Sets the maximum value (default 100).
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self.value = self.clamp(self.value);
+        self
+    }
+
+    /// Sets the amount moved by Left/Right (default 1).
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Sets the amount moved by PageUp/PageDown (default a tenth of the range).
+    pub fn page_step(mut self, step: f64) -> Self {
+        self.page_step = Some(step.abs());
+        self
+    }
+
+    /// Adds a labelled tick. Once any tick is added, the slider only stops on ticks.
+    pub fn tick(mut self, value: f64, label: impl Into<String>) -> Self {
+        self.ticks.push((value, label.into()));
+        self.ticks.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: f64) -> Self {
+        self.value = self.clamp(value);
+        self
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    /// Number of decimals needed to show the step and bounds exactly.
+    fn precision(&self) -> usize {
+        let decimals = |value: f64| {
+            value
+                .to_string()
+                .split_once('.')
+                .map_or(0, |(_, frac)| frac.len())
+        };
+        decimals(self.step)
+            .max(decimals(self.min))
+            .max(decimals(self.max))
+    }
+
+    fn format(&self, value: f64) -> String {
+        format!("{:.*}", self.precision(), value)
+    }
+
+    /// Moves by `delta` steps (or ticks), or by whole pages when `page` is set.
+    fn move_by(&mut self, delta: i32, page: bool) {
+        if !self.ticks.is_empty() {
+            self.move_to_tick(delta, page);
+            return;
+        }
+
+        let amount = if page {
+            self.page_step.unwrap_or((self.max - self.min) / 10.0)
+        } else {
+            self.step
+        };
+        let next = self.value + f64::from(delta) * amount;
+        // Snap to the step grid so repeated moves land on round values
+        let next = if self.step > 0.0 {
+            self.min + ((next - self.min) / self.step).round() * self.step
+        } else {
+            next
+        };
+        self.value = self.clamp(next);
+    }
+
+    fn move_to_tick(&mut self, delta: i32, page: bool) {
+        let current = self
+            .ticks
+            .iter()
+            .position(|(value, _)| *value >= self.value)
+            .unwrap_or(self.ticks.len());
+        // `current` is the first tick at or above the value, so from between
+        // two ticks one step right only reaches that tick
+        let on_tick = self
+            .ticks
+            .get(current)
+            .is_some_and(|(value, _)| *value == self.value);
+        let delta = if page { delta * 3 } else { delta };
+        let target = if delta > 0 && !on_tick {
+            current as i32 + delta - 1
+        } else {
+            current as i32 + delta
+        };
+        let target = target.clamp(0, self.ticks.len() as i32 - 1) as usize;
+        self.value = self.clamp(self.ticks[target].0);
+    }
+
+    /// Column of `value` on a track `width` cells wide.
+    fn position(&self, value: f64, width: u16) -> u16 {
+        let range = self.max - self.min;
+        if range <= 0.0 || width < 2 {
+            return 0;
+        }
+        let fraction = ((value - self.min) / range).clamp(0.0, 1.0);
+        (fraction * f64::from(width - 1)).round() as u16
+    }
+}
+
+impl Field for Slider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let label_text = format!("{}: ", self.label);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate track area, leaving room for the widest value on the right
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+        let value_width = self
+            .format(self.min)
+            .width()
+            .max(self.format(self.max).width()) as u16;
+        let track_width = input_width.saturating_sub(value_width + 1);
+
+        if track_width < 2 {
+            return;
+        }
+
+        let track_style = if focused {
+            style.input_focused
+        } else {
+            style.input
+        };
+
+        let tick_columns: Vec<u16> = self
+            .ticks
+            .iter()
+            .map(|(value, _)| self.position(*value, track_width))
+            .collect();
+        let thumb = self.position(self.value, track_width);
+        for column in 0..track_width {
+            let symbol = if column == thumb {
+                '●'
+            } else if tick_columns.contains(&column) {
+                '┼'
+            } else {
+                '─'
+            };
+            let cell = &mut buf[(input_x + column, area.y)];
+            cell.set_char(symbol);
+            cell.set_style(track_style);
+        }
+
+        let value_text = format!(
+            "{:>width$}",
+            self.format(self.value),
+            width = value_width as usize
+        );
+        buf.set_stringn(
+            input_x + track_width + 1,
+            area.y,
+            &value_text,
+            value_width as usize,
+            style.label,
+        );
+
+        // Render tick labels centred under their ticks, skipping any that would overlap
+        if area.height > 1 {
+            let mut next_free = input_x;
+            for ((_, label), column) in self.ticks.iter().zip(&tick_columns) {
+                let label_width = label.width() as u16;
+                let x = (input_x + column)
+                    .saturating_sub(label_width / 2)
+                    .max(next_free)
+                    .min((input_x + track_width).saturating_sub(label_width));
+                if x < next_free || x + label_width > input_x + track_width {
+                    continue;
+                }
+                buf.set_string(x, area.y + 1, label, style.placeholder);
+                next_free = x + label_width + 1;
+            }
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Left => self.move_by(-1, false),
+            KeyCode::Right => self.move_by(1, false),
+            KeyCode::PageDown => self.move_by(-1, true),
+            KeyCode::PageUp => self.move_by(1, true),
+            KeyCode::Home => {
+                self.value = self.ticks.first().map_or(self.min, |(value, _)| *value);
+            }
+            KeyCode::End => {
+                self.value = self.ticks.last().map_or(self.max, |(value, _)| *value);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn value(&self) -> Value {
+        if self.precision() == 0 {
+            Value::from(self.value.round() as i64)
+        } else {
+            // Round away float noise such as 0.30000000000000004
+            self.format(self.value)
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .unwrap_or(Value::Null)
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        // The value is always kept within bounds
+        Ok(())
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn height(&self) -> u16 {
+        if self.ticks.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+}
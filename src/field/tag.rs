@@ -0,0 +1,414 @@
+//! Tag/chip input field.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::{ValidationError, Validator};
+
+/// A field that collects a list of free-form strings as chips.
+///
+/// Enter or `,` turns the typed text into a chip and Backspace on an empty
+/// input removes the last chip. Each item is checked by the item validators
+/// before it is added. Text left in the input is not part of the value, so
+/// validation fails until it is added or cleared. The value is exported as a
+/// JSON array of strings.
+pub struct TagInput {
+    id: String,
+    label: String,
+    help: Option<String>,
+    tags: Vec<String>,
+    input: String,
+    cursor_position: usize,
+    placeholder: Option<String>,
+    item_validators: Vec<Box<dyn Validator>>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    dedupe: bool,
+    required: bool,
+    rejection: Option<ValidationError>, // why the last typed item was not added
+    validation_errors: Vec<ValidationError>,
+}
+
+impl TagInput {
+    /// Creates a new tag input field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            tags: Vec::new(),
+            input: String::new(),
+            cursor_position: 0,
+            placeholder: None,
+            item_validators: Vec::new(),
+            min_items: None,
+            max_items: None,
+            dedupe: true,
+            required: false,
+            rejection: None,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Sets a placeholder text, shown while there are no chips.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Adds a validator that every item must pass (e.g. `Email`).
+    pub fn item_validator(mut self, validator: Box<dyn Validator>) -> Self {
+        self.item_validators.push(validator);
+        self
+    }
+
+    /// Requires at least `min` items.
+    pub fn min_items(mut self, min: usize) -> Self {
+        self.min_items = Some(min);
+        self
+    }
+
+    /// Allows at most `max` items.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.max_items = Some(max);
+        self
+    }
+
+    /// Enables or disables dropping duplicate items (enabled by default).
+    pub fn dedupe(mut self, dedupe: bool) -> Self {
+        self.dedupe = dedupe;
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required (at least one item).
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the initial items.
+    pub fn initial_values(mut self, values: &[&str]) -> Self {
+        self.tags = values.iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    fn reject(&mut self, message: String) {
+        self.rejection = Some(ValidationError {
+            field_id: self.id.clone(),
+            message,
+        });
+    }
+
+    /// Runs the item validators on one item.
+    fn check_item(&self, item: &str) -> Result<(), String> {
+        self.item_validators
+            .iter()
+            .try_for_each(|validator| validator.validate(item))
+    }
+
+    /// Turns the typed text into a chip, if it is valid.
+    fn commit(&mut self) {
+        let item = self.input.trim().to_string();
+        if item.is_empty() {
+            return;
+        }
+
+        // A duplicate is dropped silently; the input is still cleared
+        if !(self.dedupe && self.tags.contains(&item)) {
+            if let Some(max) = self.max_items.filter(|max| self.tags.len() >= *max) {
+                self.reject(format!("At most {} items", max));
+                return;
+            }
+            if let Err(message) = self.check_item(&item) {
+                self.reject(message);
+                return;
+            }
+            self.tags.push(item);
+        }
+
+        self.input.clear();
+        self.cursor_position = 0;
+    }
+
+    fn insert_char(&mut self, c: char) {
+        self.input.insert(self.cursor_position, c);
+        self.cursor_position += c.len_utf8();
+    }
+
+    fn delete_char_before_cursor(&mut self) {
+        if let Some((i, _)) = self.input[..self.cursor_position].char_indices().last() {
+            self.input.remove(i);
+            self.cursor_position = i;
+        }
+    }
+
+    /// Applies an editing key. Returns false if the key is not handled.
+    fn edit(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Enter | KeyCode::Char(',') => {
+                if self.input.trim().is_empty() {
+                    return event.code == KeyCode::Char(',');
+                }
+                self.commit();
+            }
+            KeyCode::Char('u') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.input.clear();
+                self.cursor_position = 0;
+            }
+            KeyCode::Char(_) if event.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char(c) => self.insert_char(c),
+            KeyCode::Backspace => {
+                if self.cursor_position > 0 {
+                    self.delete_char_before_cursor();
+                } else if self.input.is_empty() {
+                    self.tags.pop();
+                }
+            }
+            KeyCode::Delete => {
+                if self.cursor_position < self.input.len() {
+                    self.input.remove(self.cursor_position);
+                }
+            }
+            KeyCode::Left => {
+                if let Some((i, _)) = self.input[..self.cursor_position].char_indices().last() {
+                    self.cursor_position = i;
+                }
+            }
+            KeyCode::Right => {
+                if let Some(c) = self.input[self.cursor_position..].chars().next() {
+                    self.cursor_position += c.len_utf8();
+                }
+            }
+            KeyCode::Home => self.cursor_position = 0,
+            KeyCode::End => self.cursor_position = self.input.len(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn shown_errors(&self) -> &[ValidationError] {
+        match self.rejection {
+            Some(ref rejection) => std::slice::from_ref(rejection),
+            None => &self.validation_errors,
+        }
+    }
+}
+
+impl Field for TagInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate input area
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+
+        if input_width == 0 {
+            return;
+        }
+
+        let input_style = if focused {
+            style.input_focused
+        } else {
+            style.input
+        };
+
+        // Fill input area with background
+        for x in input_x..input_x + input_width {
+            buf[(x, area.y)].set_style(input_style);
+            buf[(x, area.y)].set_char(' ');
+        }
+
+        // Keep the typed text visible by dropping the oldest chips that don't fit
+        let text_width = self.input.width() as u16 + 1;
+        let mut available = input_width.saturating_sub(text_width);
+        let mut first = self.tags.len();
+        while first > 0 {
+            let chip_width = self.tags[first - 1].width() as u16 + 3;
+            // Reserve room for the "+N " overflow marker
+            let marker_width = if first > 1 { 4 } else { 0 };
+            if chip_width + marker_width > available {
+                break;
+            }
+            available -= chip_width;
+            first -= 1;
+        }
+
+        let mut x = input_x;
+        let right = input_x + input_width;
+        if first > 0 {
+            let marker = format!("+{} ", first);
+            buf.set_stringn(x, area.y, &marker, (right - x) as usize, style.placeholder);
+            x = (x + marker.width() as u16).min(right);
+        }
+
+        let chip_style = Style::default().bg(Color::DarkGray).fg(Color::White);
+        for tag in &self.tags[first..] {
+            let chip = format!(" {} ", tag);
+            buf.set_stringn(x, area.y, &chip, (right - x) as usize, chip_style);
+            x = (x + chip.width() as u16 + 1).min(right);
+        }
+
+        // Render the typed text or placeholder
+        match self.placeholder {
+            Some(ref placeholder) if self.tags.is_empty() && self.input.is_empty() => {
+                buf.set_stringn(
+                    x,
+                    area.y,
+                    placeholder,
+                    (right - x) as usize,
+                    style.placeholder,
+                );
+            }
+            _ => {
+                buf.set_stringn(x, area.y, &self.input, (right - x) as usize, style.input);
+            }
+        }
+
+        // Render cursor if focused
+        if focused {
+            let cursor_x = x + self.input[..self.cursor_position].width() as u16;
+            if cursor_x < right {
+                buf[(cursor_x, area.y)].set_style(
+                    Style::default()
+                        .bg(Color::White)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::SLOW_BLINK),
+                );
+            }
+        }
+
+        // Render the rejected item or validation errors below the input
+        if area.height > 1 {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + 1,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(self.shown_errors(), error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        // Any edit clears the message about a rejected item, but keys the
+        // field does not handle (such as Tab) leave it shown
+        let rejection = self.rejection.take();
+        let consumed = self.edit(event);
+        if !consumed {
+            self.rejection = rejection;
+        }
+        consumed
+    }
+
+    fn value(&self) -> Value {
+        Value::Array(self.tags.iter().cloned().map(Value::String).collect())
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let mut error = |message: String| {
+            errors.push(ValidationError {
+                field_id: self.id.clone(),
+                message,
+            })
+        };
+
+        // Typed text only becomes an item on Enter or `,`
+        let pending = self.input.trim();
+        if !pending.is_empty() {
+            error(format!("Press Enter to add \"{}\"", pending));
+        }
+
+        let count = self.tags.len();
+        if self.required && count == 0 && pending.is_empty() {
+            error(format!("{} is required", self.label));
+        } else if let Some(min) = self.min_items.filter(|min| count < *min) {
+            error(format!("Add at least {} items", min));
+        }
+        if let Some(max) = self.max_items.filter(|max| count > *max) {
+            error(format!("At most {} items", max));
+        }
+
+        // Initial values never went through the item validators
+        for tag in &self.tags {
+            if let Err(message) = self.check_item(tag) {
+                error(format!("{}: {}", tag, message));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn height(&self) -> u16 {
+        if self.shown_errors().is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
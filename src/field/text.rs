@@ -9,17 +9,24 @@ use ratatui::widgets::Widget;
 use serde_json::Value;
 use unicode_width::UnicodeWidthStr;
 
-use crate::field::Field;
+use crate::field::{render_error_line, Field};
 use crate::style::FormStyle;
 use crate::validation::{ValidationError, Validator};
 
 /// A single-line text input field.
+///
+/// With [`TextInput::masked`] the field becomes a password input: the real value
+/// is stored but every character is drawn as the mask character.
 pub struct TextInput {
     id: String,
     label: String,
+    help: Option<String>,
     value: String,
     cursor_position: usize,
     placeholder: Option<String>,
+    mask: Option<char>,
+    revealable: bool,
+    revealed: bool,
     required: bool,
     validators: Vec<Box<dyn Validator>>,
     validation_errors: Vec<ValidationError>,
@@ -31,9 +38,13 @@ impl TextInput {
         Self {
             id: id.into(),
             label: label.into(),
+            help: None,
             value: String::new(),
             cursor_position: 0,
             placeholder: None,
+            mask: None,
+            revealable: false,
+            revealed: false,
             required: false,
             validators: Vec::new(),
             validation_errors: Vec::new(),
@@ -46,6 +57,24 @@ impl TextInput {
         self
     }
 
+    /// Hides the value by drawing each character as `mask` (e.g. `'•'`).
+    pub fn masked(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Lets Ctrl+R temporarily reveal a masked value while the field is focused.
+    pub fn revealable(mut self) -> Self {
+        self.revealable = true;
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
     /// Marks this field as required.
     pub fn required(mut self) -> Self {
         self.required = true;
@@ -65,6 +94,11 @@ impl TextInput {
         self
     }
 
+    /// Returns the mask character if the value should currently be hidden.
+    fn active_mask(&self, focused: bool) -> Option<char> {
+        self.mask.filter(|_| !(self.revealed && focused))
+    }
+
     fn insert_char(&mut self, c: char) {
         self.value.insert(self.cursor_position, c);
         self.cursor_position += c.len_utf8();
@@ -161,12 +195,17 @@ impl Field for TextInput {
         }
 
         // Determine what to display
+        let mask = self.active_mask(focused);
+        let masked_value;
         let (display_text, display_style) = if self.value.is_empty() {
             if let Some(ref placeholder) = self.placeholder {
                 (placeholder.as_str(), style.placeholder)
             } else {
                 ("", style.input)
             }
+        } else if let Some(mask) = mask {
+            masked_value = mask.to_string().repeat(self.value.chars().count());
+            (masked_value.as_str(), style.input)
         } else {
             (self.value.as_str(), style.input)
         };
@@ -196,7 +235,12 @@ impl Field for TextInput {
 
         // Render cursor if focused
         if focused {
-            let cursor_x = input_x + self.value[..self.cursor_position].width() as u16;
+            let before_cursor = &self.value[..self.cursor_position];
+            let cursor_offset = match mask {
+                Some(mask) => before_cursor.chars().count() * mask.to_string().width(),
+                None => before_cursor.width(),
+            };
+            let cursor_x = input_x + cursor_offset as u16;
             if cursor_x < area.x + area.width {
                 buf[(cursor_x, area.y)].set_style(
                     Style::default()
@@ -208,17 +252,14 @@ impl Field for TextInput {
         }
 
         // Render validation errors if any
-        if !self.validation_errors.is_empty() && area.height > 1 {
-            let error_msg = &self.validation_errors[0].message;
-            let error_span = Span::styled(error_msg, style.error);
-            let error_line = Line::from(error_span);
+        if area.height > 1 {
             let error_area = Rect {
                 x: input_x,
                 y: area.y + 1,
                 width: input_width,
                 height: 1,
             };
-            error_line.render(error_area, buf);
+            render_error_line(&self.validation_errors, error_area, buf, style);
         }
     }
 
@@ -233,6 +274,9 @@ impl Field for TextInput {
                             self.value.clear();
                             self.cursor_position = 0;
                         }
+                        'r' if self.mask.is_some() && self.revealable => {
+                            self.revealed = !self.revealed;
+                        }
                         _ => return false,
                     }
                 } else {
@@ -308,7 +352,23 @@ impl Field for TextInput {
         }
     }
 
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
     fn is_required(&self) -> bool {
         self.required
     }
+
+    fn is_sensitive(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
 }
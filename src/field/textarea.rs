@@ -0,0 +1,505 @@
+//! Multi-line text area field.
+
+use std::sync::atomic::{AtomicU16, Ordering};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::{ValidationError, Validator};
+
+/// A multi-line text input field with soft wrapping and vertical scrolling.
+pub struct TextArea {
+    id: String,
+    label: String,
+    help: Option<String>,
+    value: String,
+    cursor_position: usize,
+    placeholder: Option<String>,
+    rows: u16,
+    max_lines: Option<usize>,
+    required: bool,
+    validators: Vec<Box<dyn Validator>>,
+    validation_errors: Vec<ValidationError>,
+    // Updated during render, which only has shared access to the field.
+    wrap_width: AtomicU16,
+    scroll_offset: AtomicU16,
+}
+
+impl TextArea {
+    /// Creates a new text area field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            value: String::new(),
+            cursor_position: 0,
+            placeholder: None,
+            rows: 4,
+            max_lines: None,
+            required: false,
+            validators: Vec::new(),
+            validation_errors: Vec::new(),
+            wrap_width: AtomicU16::new(0),
+            scroll_offset: AtomicU16::new(0),
+        }
+    }
+
+    /// Sets a placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the number of visible rows (default 4).
+    pub fn rows(mut self, rows: u16) -> Self {
+        self.rows = rows.max(1);
+        self
+    }
+
+    /// Limits the number of lines the user can enter.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines.max(1));
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Adds a validator to this field. Validators receive the full multi-line value.
+    pub fn validator(mut self, validator: Box<dyn Validator>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self.cursor_position = self.value.len();
+        self
+    }
+
+    fn line_count(&self) -> usize {
+        self.value.split('\n').count()
+    }
+
+    /// Splits the value into visual rows of at most `width` columns.
+    /// Each row is returned as a byte range into the value (newlines excluded).
+    fn visual_rows(&self, width: usize) -> Vec<(usize, usize)> {
+        let width = width.max(1);
+        let mut rows = Vec::new();
+        let mut line_start = 0;
+
+        for line in self.value.split('\n') {
+            let mut row_start = line_start;
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let char_width = c.width().unwrap_or(0);
+                if row_width + char_width > width && row_width > 0 {
+                    rows.push((row_start, line_start + i));
+                    row_start = line_start + i;
+                    row_width = 0;
+                }
+                row_width += char_width;
+            }
+            rows.push((row_start, line_start + line.len()));
+            line_start += line.len() + 1;
+        }
+
+        rows
+    }
+
+    fn effective_wrap_width(&self) -> usize {
+        match self.wrap_width.load(Ordering::Relaxed) {
+            0 => usize::MAX,
+            width => width as usize,
+        }
+    }
+
+    fn cursor_row(&self, rows: &[(usize, usize)]) -> usize {
+        rows.iter()
+            .rposition(|(start, _)| *start <= self.cursor_position)
+            .unwrap_or(0)
+    }
+
+    /// Returns the byte offset in `rows[row]` closest to display column `col`.
+    fn offset_at_column(&self, rows: &[(usize, usize)], row: usize, col: usize) -> usize {
+        let (start, end) = rows[row];
+        let mut offset = start;
+        let mut width = 0;
+        for (i, c) in self.value[start..end].char_indices() {
+            let char_width = c.width().unwrap_or(0);
+            if width + char_width > col {
+                break;
+            }
+            width += char_width;
+            offset = start + i + c.len_utf8();
+        }
+
+        // The end of a soft-wrapped row is the start of the next one; stay on this row.
+        let wraps = rows.get(row + 1).is_some_and(|(next, _)| *next == end);
+        if offset == end && wraps && offset > start {
+            offset = self.value[..offset]
+                .char_indices()
+                .last()
+                .map(|(i, _)| i)
+                .unwrap_or(start);
+        }
+        offset
+    }
+
+    fn move_cursor_vertical(&mut self, down: bool) -> bool {
+        let rows = self.visual_rows(self.effective_wrap_width());
+        let row = self.cursor_row(&rows);
+        let target = if down {
+            if row + 1 >= rows.len() {
+                return false;
+            }
+            row + 1
+        } else {
+            if row == 0 {
+                return false;
+            }
+            row - 1
+        };
+
+        let col = self.value[rows[row].0..self.cursor_position].width();
+        self.cursor_position = self.offset_at_column(&rows, target, col);
+        true
+    }
+
+    fn insert_char(&mut self, c: char) {
+        self.value.insert(self.cursor_position, c);
+        self.cursor_position += c.len_utf8();
+    }
+
+    fn insert_newline(&mut self) {
+        if self.max_lines.is_some_and(|max| self.line_count() >= max) {
+            return;
+        }
+        self.insert_char('\n');
+    }
+
+    fn delete_char_before_cursor(&mut self) {
+        if self.cursor_position > 0 {
+            let prev_char_boundary = self.value[..self.cursor_position]
+                .char_indices()
+                .last()
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            self.value.remove(prev_char_boundary);
+            self.cursor_position = prev_char_boundary;
+        }
+    }
+
+    fn delete_char_at_cursor(&mut self) {
+        if self.cursor_position < self.value.len() {
+            self.value.remove(self.cursor_position);
+        }
+    }
+
+    fn move_cursor_left(&mut self) {
+        if self.cursor_position > 0 {
+            self.cursor_position = self.value[..self.cursor_position]
+                .char_indices()
+                .last()
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+        }
+    }
+
+    fn move_cursor_right(&mut self) {
+        if self.cursor_position < self.value.len() {
+            self.cursor_position = self.value[self.cursor_position..]
+                .char_indices()
+                .nth(1)
+                .map(|(i, _)| self.cursor_position + i)
+                .unwrap_or(self.value.len());
+        }
+    }
+
+    fn move_cursor_line_start(&mut self) {
+        self.cursor_position = self.value[..self.cursor_position]
+            .rfind('\n')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+    }
+
+    fn move_cursor_line_end(&mut self) {
+        self.cursor_position = self.value[self.cursor_position..]
+            .find('\n')
+            .map(|i| self.cursor_position + i)
+            .unwrap_or(self.value.len());
+    }
+}
+
+impl Field for TextArea {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate input area; the last column is reserved for scroll indicators
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+        let input_height = self.rows.min(area.height);
+
+        if input_width < 2 {
+            return;
+        }
+
+        let text_width = input_width - 1;
+        self.wrap_width.store(text_width, Ordering::Relaxed);
+
+        let input_bg_style = if focused {
+            style.input_focused
+        } else {
+            style.input
+        };
+
+        // Fill input area with background
+        for y in area.y..area.y + input_height {
+            for x in input_x..input_x + input_width {
+                buf[(x, y)].set_style(input_bg_style);
+                buf[(x, y)].set_char(' ');
+            }
+        }
+
+        let rows = self.visual_rows(text_width as usize);
+        let cursor_row = self.cursor_row(&rows);
+
+        // Keep the cursor row inside the visible window
+        let mut scroll = self.scroll_offset.load(Ordering::Relaxed) as usize;
+        if cursor_row < scroll {
+            scroll = cursor_row;
+        } else if cursor_row >= scroll + input_height as usize {
+            scroll = cursor_row + 1 - input_height as usize;
+        }
+        scroll = scroll.min(rows.len().saturating_sub(input_height as usize));
+        self.scroll_offset.store(scroll as u16, Ordering::Relaxed);
+
+        if self.value.is_empty() {
+            if let Some(ref placeholder) = self.placeholder {
+                let visible: String = placeholder.chars().take(text_width as usize).collect();
+                Line::from(Span::styled(visible, style.placeholder)).render(
+                    Rect {
+                        x: input_x,
+                        y: area.y,
+                        width: text_width,
+                        height: 1,
+                    },
+                    buf,
+                );
+            }
+        } else {
+            for (i, (start, end)) in rows
+                .iter()
+                .skip(scroll)
+                .take(input_height as usize)
+                .enumerate()
+            {
+                let y = area.y + i as u16;
+                let mut x = input_x;
+                for c in self.value[*start..*end].chars() {
+                    let char_width = c.width().unwrap_or(0) as u16;
+                    if x + char_width > input_x + text_width {
+                        break;
+                    }
+                    buf[(x, y)].set_char(c);
+                    buf[(x, y)].set_style(style.input);
+                    x += char_width.max(1);
+                }
+            }
+        }
+
+        // Render scroll indicators
+        let indicator_x = input_x + text_width;
+        if scroll > 0 {
+            buf[(indicator_x, area.y)].set_char('▲');
+        }
+        if scroll + (input_height as usize) < rows.len() {
+            buf[(indicator_x, area.y + input_height - 1)].set_char('▼');
+        }
+
+        // Render cursor if focused
+        if focused {
+            let (row_start, _) = rows[cursor_row];
+            let cursor_x = input_x + self.value[row_start..self.cursor_position].width() as u16;
+            let cursor_y = area.y + (cursor_row - scroll) as u16;
+            if cursor_x < input_x + input_width && cursor_y < area.y + input_height {
+                buf[(cursor_x, cursor_y)].set_style(
+                    Style::default()
+                        .bg(Color::White)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::SLOW_BLINK),
+                );
+            }
+        }
+
+        // Render validation errors if any
+        if area.height > input_height {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + input_height,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Char(c) => {
+                if event.modifiers.contains(KeyModifiers::CONTROL) {
+                    match c {
+                        'a' => self.move_cursor_line_start(),
+                        'e' => self.move_cursor_line_end(),
+                        'u' => {
+                            self.value.clear();
+                            self.cursor_position = 0;
+                        }
+                        _ => return false,
+                    }
+                } else {
+                    self.insert_char(c);
+                }
+                true
+            }
+            KeyCode::Enter => {
+                self.insert_newline();
+                true
+            }
+            KeyCode::Backspace => {
+                self.delete_char_before_cursor();
+                true
+            }
+            KeyCode::Delete => {
+                self.delete_char_at_cursor();
+                true
+            }
+            KeyCode::Left => {
+                self.move_cursor_left();
+                true
+            }
+            KeyCode::Right => {
+                self.move_cursor_right();
+                true
+            }
+            // Leaving the first/last row hands focus back to the form
+            KeyCode::Up => self.move_cursor_vertical(false),
+            KeyCode::Down => self.move_cursor_vertical(true),
+            KeyCode::Home => {
+                self.move_cursor_line_start();
+                true
+            }
+            KeyCode::End => {
+                self.move_cursor_line_end();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::String(self.value.clone())
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        // Check required
+        if self.required && self.value.trim().is_empty() {
+            errors.push(ValidationError {
+                field_id: self.id.clone(),
+                message: format!("{} is required", self.label),
+            });
+        }
+
+        // Run validators
+        for validator in &self.validators {
+            if let Err(msg) = validator.validate(&self.value) {
+                errors.push(ValidationError {
+                    field_id: self.id.clone(),
+                    message: msg,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn height(&self) -> u16 {
+        if self.validation_errors.is_empty() {
+            self.rows
+        } else {
+            self.rows + 1
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
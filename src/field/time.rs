@@ -0,0 +1,1012 @@
+//! Time and date-time fields with segment-wise editing.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::date::{days_in_month, CalendarDate};
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// Largest timezone offset in use, in minutes (UTC+14:00).
+const MAX_OFFSET_MINUTES: u32 = 14 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SegmentKind {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Meridiem,
+    OffsetSign,
+    OffsetHour,
+    OffsetMinute,
+}
+
+impl SegmentKind {
+    fn width(self) -> usize {
+        match self {
+            SegmentKind::Year => 4,
+            SegmentKind::OffsetSign => 1,
+            _ => 2,
+        }
+    }
+
+    fn placeholder(self) -> &'static str {
+        match self {
+            SegmentKind::Year => "YYYY",
+            SegmentKind::Month | SegmentKind::Minute | SegmentKind::OffsetMinute => "MM",
+            SegmentKind::Day => "DD",
+            SegmentKind::Hour | SegmentKind::OffsetHour => "HH",
+            SegmentKind::Second => "SS",
+            SegmentKind::Meridiem => "AM",
+            SegmentKind::OffsetSign => "±",
+        }
+    }
+
+    /// Text drawn between the previous segment and this one.
+    fn separator(self) -> &'static str {
+        match self {
+            SegmentKind::Year | SegmentKind::OffsetHour => "",
+            SegmentKind::Month | SegmentKind::Day => "-",
+            SegmentKind::Minute | SegmentKind::Second | SegmentKind::OffsetMinute => ":",
+            SegmentKind::Hour | SegmentKind::Meridiem | SegmentKind::OffsetSign => " ",
+        }
+    }
+
+    fn is_date(self) -> bool {
+        matches!(
+            self,
+            SegmentKind::Year | SegmentKind::Month | SegmentKind::Day
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    kind: SegmentKind,
+    value: Option<u32>,
+}
+
+/// Editing state shared by [`TimeInput`] and [`DateTimeInput`].
+///
+/// The value is held as a row of numeric segments (year, hour, minute, ...).
+/// Left/Right move between segments, Up/Down step the active one, and typed
+/// digits fill it, advancing once it is full.
+struct Segments {
+    date: bool,
+    seconds: bool,
+    twelve_hour: bool,
+    offset: bool,
+    initial: Option<String>,
+    segments: Vec<Segment>,
+    active: usize,
+    typed: usize,
+}
+
+impl Segments {
+    fn new(date: bool) -> Self {
+        let mut segments = Self {
+            date,
+            seconds: false,
+            twelve_hour: false,
+            offset: false,
+            initial: None,
+            segments: Vec::new(),
+            active: 0,
+            typed: 0,
+        };
+        segments.rebuild();
+        segments
+    }
+
+    /// Lays out the segments for the current options and reloads the
+    /// initial value, so builder methods can be called in any order.
+    fn rebuild(&mut self) {
+        let mut kinds = Vec::new();
+        if self.date {
+            kinds.extend([SegmentKind::Year, SegmentKind::Month, SegmentKind::Day]);
+        }
+        kinds.extend([SegmentKind::Hour, SegmentKind::Minute]);
+        if self.seconds {
+            kinds.push(SegmentKind::Second);
+        }
+        if self.twelve_hour {
+            kinds.push(SegmentKind::Meridiem);
+        }
+        if self.offset {
+            kinds.extend([
+                SegmentKind::OffsetSign,
+                SegmentKind::OffsetHour,
+                SegmentKind::OffsetMinute,
+            ]);
+        }
+
+        self.segments = kinds
+            .into_iter()
+            .map(|kind| Segment { kind, value: None })
+            .collect();
+        self.active = 0;
+        self.typed = 0;
+
+        if let Some(initial) = self.initial.clone() {
+            self.load(&initial);
+        }
+    }
+
+    fn position(&self, kind: SegmentKind) -> Option<usize> {
+        self.segments
+            .iter()
+            .position(|segment| segment.kind == kind)
+    }
+
+    fn get(&self, kind: SegmentKind) -> Option<u32> {
+        self.position(kind).and_then(|i| self.segments[i].value)
+    }
+
+    fn set(&mut self, kind: SegmentKind, value: u32) {
+        if let Some(i) = self.position(kind) {
+            self.segments[i].value = Some(value);
+        }
+    }
+
+    /// Returns the allowed range of the segment at `index`.
+    fn bounds(&self, index: usize) -> (u32, u32) {
+        match self.segments[index].kind {
+            SegmentKind::Year => (1, 9999),
+            SegmentKind::Month => (1, 12),
+            SegmentKind::Day => {
+                let max = match (self.get(SegmentKind::Year), self.get(SegmentKind::Month)) {
+                    (Some(year), Some(month)) if (1..=12).contains(&month) => {
+                        days_in_month(year as i32, month)
+                    }
+                    _ => 31,
+                };
+                (1, max)
+            }
+            SegmentKind::Hour if self.twelve_hour => (1, 12),
+            SegmentKind::Hour => (0, 23),
+            SegmentKind::Minute | SegmentKind::Second | SegmentKind::OffsetMinute => (0, 59),
+            SegmentKind::Meridiem | SegmentKind::OffsetSign => (0, 1),
+            SegmentKind::OffsetHour => (0, 14),
+        }
+    }
+
+    /// Loads an ISO 8601 value, ignoring parts that do not parse.
+    fn load(&mut self, value: &str) {
+        let time = if self.date {
+            let Some(date) = value.get(..10).and_then(CalendarDate::parse) else {
+                return;
+            };
+            self.set(SegmentKind::Year, date.year as u32);
+            self.set(SegmentKind::Month, date.month);
+            self.set(SegmentKind::Day, date.day);
+            value.get(11..).unwrap_or("")
+        } else {
+            value
+        };
+
+        // Split off a trailing `Z` or `±HH:MM` offset
+        let (clock, offset) = match time.find(['Z', 'z', '+', '-']) {
+            Some(i) => (&time[..i], Some(&time[i..])),
+            None => (time, None),
+        };
+
+        let parts: Vec<Option<u32>> = clock.split(':').map(|part| part.parse().ok()).collect();
+        if let Some(Some(hour)) = parts.first().copied().filter(|_| parts.len() >= 2) {
+            if self.twelve_hour {
+                self.set(
+                    SegmentKind::Hour,
+                    if hour % 12 == 0 { 12 } else { hour % 12 },
+                );
+                self.set(SegmentKind::Meridiem, u32::from(hour >= 12));
+            } else {
+                self.set(SegmentKind::Hour, hour);
+            }
+        }
+        if let Some(Some(minute)) = parts.get(1) {
+            self.set(SegmentKind::Minute, *minute);
+        }
+        if let Some(Some(second)) = parts.get(2) {
+            self.set(SegmentKind::Second, *second);
+        }
+
+        match offset {
+            Some("Z" | "z") => {
+                self.set(SegmentKind::OffsetSign, 0);
+                self.set(SegmentKind::OffsetHour, 0);
+                self.set(SegmentKind::OffsetMinute, 0);
+            }
+            Some(offset) => {
+                let (sign, rest) = offset.split_at(1);
+                let mut parts = rest.split(':').map(|part| part.parse::<u32>().ok());
+                if let (Some(Some(hours)), Some(Some(minutes))) = (parts.next(), parts.next()) {
+                    self.set(SegmentKind::OffsetSign, u32::from(sign == "-"));
+                    self.set(SegmentKind::OffsetHour, hours);
+                    self.set(SegmentKind::OffsetMinute, minutes);
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn clear(&mut self) {
+        for segment in &mut self.segments {
+            segment.value = None;
+        }
+        self.active = 0;
+        self.typed = 0;
+    }
+
+    fn move_to(&mut self, index: usize) {
+        self.active = index.min(self.segments.len() - 1);
+        self.typed = 0;
+    }
+
+    fn advance(&mut self) {
+        self.move_to(self.active + 1);
+    }
+
+    fn type_digit(&mut self, digit: u32) {
+        let (_, max) = self.bounds(self.active);
+        let segment = &mut self.segments[self.active];
+
+        let current = match segment.value {
+            Some(value) if self.typed > 0 => value * 10 + digit,
+            _ => digit,
+        };
+        // A digit that would overflow the segment starts it over
+        let (value, typed) = if current > max {
+            (digit, 1)
+        } else {
+            (current, self.typed + 1)
+        };
+        segment.value = Some(value);
+        self.typed = typed;
+
+        // Move on once no further digit could fit
+        if typed >= segment.kind.width() || value * 10 > max {
+            self.advance();
+        }
+    }
+
+    fn step(&mut self, up: bool) {
+        let (min, max) = self.bounds(self.active);
+        let kind = self.segments[self.active].kind;
+        let value = match self.segments[self.active].value {
+            None => match kind {
+                SegmentKind::Year => CalendarDate::today().year as u32,
+                SegmentKind::Month => CalendarDate::today().month,
+                SegmentKind::Day => CalendarDate::today().day.min(max),
+                _ if up => min,
+                _ => max,
+            },
+            Some(value) if up => {
+                if value >= max {
+                    min
+                } else {
+                    value + 1
+                }
+            }
+            Some(value) => {
+                if value <= min {
+                    max
+                } else {
+                    (value - 1).min(max)
+                }
+            }
+        };
+        self.segments[self.active].value = Some(value);
+        self.typed = 0;
+    }
+
+    /// Sets the offset sign and moves to the offset hours.
+    fn enter_offset(&mut self, negative: bool) {
+        if let Some(sign) = self.position(SegmentKind::OffsetSign) {
+            self.segments[sign].value = Some(u32::from(negative));
+            self.move_to(sign + 1);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        let kind = self.segments[self.active].kind;
+
+        match event.code {
+            KeyCode::Char('u') if event.modifiers.contains(KeyModifiers::CONTROL) => self.clear(),
+            KeyCode::Char(_) if event.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Left => self.move_to(self.active.saturating_sub(1)),
+            KeyCode::Right => self.advance(),
+            KeyCode::Home => self.move_to(0),
+            KeyCode::End => self.move_to(self.segments.len() - 1),
+            KeyCode::Up => self.step(true),
+            KeyCode::Down => self.step(false),
+            KeyCode::Backspace => {
+                if self.segments[self.active].value.is_some() {
+                    self.segments[self.active].value = None;
+                    self.typed = 0;
+                } else {
+                    self.move_to(self.active.saturating_sub(1));
+                }
+            }
+            KeyCode::Delete => {
+                self.segments[self.active].value = None;
+                self.typed = 0;
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                let digit = c.to_digit(10).unwrap_or(0);
+                match kind {
+                    SegmentKind::OffsetSign => {
+                        self.enter_offset(self.segments[self.active].value == Some(1));
+                        self.type_digit(digit);
+                    }
+                    SegmentKind::Meridiem => {}
+                    _ => self.type_digit(digit),
+                }
+            }
+            KeyCode::Char(c @ ('a' | 'A' | 'p' | 'P')) if self.twelve_hour => {
+                self.set(
+                    SegmentKind::Meridiem,
+                    u32::from(c.eq_ignore_ascii_case(&'p')),
+                );
+                if kind == SegmentKind::Meridiem {
+                    self.advance();
+                }
+            }
+            KeyCode::Char('z' | 'Z') if self.offset => {
+                self.set(SegmentKind::OffsetSign, 0);
+                self.set(SegmentKind::OffsetHour, 0);
+                self.set(SegmentKind::OffsetMinute, 0);
+                self.move_to(self.segments.len() - 1);
+            }
+            KeyCode::Char(c @ ('+' | '-')) if self.offset && !kind.is_date() => {
+                self.enter_offset(c == '-');
+            }
+            KeyCode::Char(':' | '-' | '/' | '.' | 'T' | 't' | ' ') => self.advance(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn is_empty(&self) -> bool {
+        self.segments
+            .iter()
+            .filter(|segment| segment.kind != SegmentKind::OffsetSign)
+            .all(|segment| segment.value.is_none())
+    }
+
+    /// Returns the format shown when the value is incomplete.
+    fn format_hint(&self) -> String {
+        self.segments
+            .iter()
+            .enumerate()
+            .map(|(i, segment)| {
+                let separator = if i == 0 { "" } else { segment.kind.separator() };
+                format!("{}{}", separator, segment.kind.placeholder())
+            })
+            .collect()
+    }
+
+    /// Builds the ISO 8601 value, or `None` when nothing has been entered.
+    fn to_iso(&self) -> Result<Option<String>, String> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let offset_entered = self.get(SegmentKind::OffsetHour).is_some()
+            || self.get(SegmentKind::OffsetMinute).is_some();
+        let incomplete = self.segments.iter().any(|segment| {
+            segment.value.is_none()
+                && match segment.kind {
+                    SegmentKind::OffsetSign => false,
+                    SegmentKind::OffsetHour | SegmentKind::OffsetMinute => offset_entered,
+                    _ => true,
+                }
+        });
+        if incomplete {
+            return Err(format!("Incomplete value (use {})", self.format_hint()));
+        }
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            let (min, max) = self.bounds(i);
+            if segment
+                .value
+                .is_some_and(|value| value < min || value > max)
+            {
+                return Err(if segment.kind.is_date() {
+                    "Invalid date".to_string()
+                } else {
+                    "Invalid time".to_string()
+                });
+            }
+        }
+
+        let mut iso = String::new();
+        if self.date {
+            let date = CalendarDate::new(
+                self.get(SegmentKind::Year).unwrap_or(0) as i32,
+                self.get(SegmentKind::Month).unwrap_or(0),
+                self.get(SegmentKind::Day).unwrap_or(0),
+            )
+            .ok_or_else(|| "Invalid date".to_string())?;
+            iso.push_str(&format!("{}T", date));
+        }
+
+        let mut hour = self.get(SegmentKind::Hour).unwrap_or(0);
+        if self.twelve_hour {
+            hour = hour % 12 + 12 * self.get(SegmentKind::Meridiem).unwrap_or(0);
+        }
+        iso.push_str(&format!(
+            "{:02}:{:02}",
+            hour,
+            self.get(SegmentKind::Minute).unwrap_or(0)
+        ));
+        if let Some(second) = self.get(SegmentKind::Second) {
+            iso.push_str(&format!(":{:02}", second));
+        }
+
+        if offset_entered {
+            let hours = self.get(SegmentKind::OffsetHour).unwrap_or(0);
+            let minutes = self.get(SegmentKind::OffsetMinute).unwrap_or(0);
+            if hours * 60 + minutes > MAX_OFFSET_MINUTES {
+                return Err("Invalid timezone offset".to_string());
+            }
+            let sign = if self.get(SegmentKind::OffsetSign) == Some(1) {
+                '-'
+            } else {
+                '+'
+            };
+            iso.push_str(&format!("{}{:02}:{:02}", sign, hours, minutes));
+        }
+
+        Ok(Some(iso))
+    }
+
+    fn render(
+        &self,
+        x: u16,
+        y: u16,
+        width: u16,
+        buf: &mut Buffer,
+        focused: bool,
+        style: &FormStyle,
+    ) {
+        let right = x + width;
+        let mut col = x;
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 {
+                let separator = segment.kind.separator();
+                buf.set_stringn(col, y, separator, (right - col) as usize, style.input);
+                col = (col + separator.width() as u16).min(right);
+            }
+
+            let (text, text_style) = match (segment.kind, segment.value) {
+                (_, None) => (segment.kind.placeholder().to_string(), style.placeholder),
+                (SegmentKind::Meridiem, Some(value)) => (
+                    if value == 0 { "AM" } else { "PM" }.to_string(),
+                    style.input,
+                ),
+                (SegmentKind::OffsetSign, Some(value)) => {
+                    (if value == 0 { "+" } else { "-" }.to_string(), style.input)
+                }
+                (kind, Some(value)) => (
+                    format!("{:0width$}", value, width = kind.width()),
+                    style.input,
+                ),
+            };
+
+            // Highlight the segment being edited
+            let text_style = if focused && i == self.active {
+                Style::default()
+                    .bg(Color::White)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::SLOW_BLINK)
+            } else {
+                text_style
+            };
+            buf.set_stringn(col, y, &text, (right - col) as usize, text_style);
+            col = (col + text.width() as u16).min(right);
+        }
+    }
+}
+
+/// Renders the label, input background and segments shared by both fields.
+#[allow(clippy::too_many_arguments)]
+fn render_segmented(
+    label: &str,
+    required: bool,
+    segments: &Segments,
+    errors: &[ValidationError],
+    area: Rect,
+    buf: &mut Buffer,
+    focused: bool,
+    style: &FormStyle,
+) {
+    if area.height < 1 || area.width < 1 {
+        return;
+    }
+
+    // Render label
+    let label_style = if focused {
+        style.label_focused
+    } else {
+        style.label
+    };
+
+    let required_marker = if required { "*" } else { "" };
+    let label_text = format!("{}{}: ", label, required_marker);
+    let label_width = label_text.width().min(area.width as usize);
+
+    let label_span = Span::styled(&label_text, label_style);
+    let label_line = Line::from(label_span);
+    let label_area = Rect {
+        x: area.x,
+        y: area.y,
+        width: label_width as u16,
+        height: 1,
+    };
+    label_line.render(label_area, buf);
+
+    // Calculate input area
+    let input_x = area.x + label_width as u16;
+    let input_width = area.width.saturating_sub(label_width as u16);
+
+    if input_width == 0 {
+        return;
+    }
+
+    let input_style = if focused {
+        style.input_focused
+    } else {
+        style.input
+    };
+
+    // Fill input area with background
+    for x in input_x..input_x + input_width {
+        buf[(x, area.y)].set_style(input_style);
+        buf[(x, area.y)].set_char(' ');
+    }
+
+    segments.render(input_x, area.y, input_width, buf, focused, style);
+
+    // Render validation errors below the input
+    if area.height > 1 {
+        let error_area = Rect {
+            x: input_x,
+            y: area.y + 1,
+            width: input_width,
+            height: 1,
+        };
+        render_error_line(errors, error_area, buf, style);
+    }
+}
+
+fn validate_segmented(
+    id: &str,
+    label: &str,
+    required: bool,
+    segments: &Segments,
+) -> Result<(), Vec<ValidationError>> {
+    let message = match segments.to_iso() {
+        Ok(None) if required => format!("{} is required", label),
+        Ok(_) => return Ok(()),
+        Err(message) => message,
+    };
+
+    Err(vec![ValidationError {
+        field_id: id.to_string(),
+        message,
+    }])
+}
+
+/// A time-of-day field edited one segment at a time.
+///
+/// Left/Right move between the hour, minute and second segments, Up/Down step
+/// the active segment, and typed digits fill it. The value is exported as an
+/// ISO 8601 time such as `"14:30"`, `"14:30:05"` or `"14:30+02:00"`.
+pub struct TimeInput {
+    id: String,
+    label: String,
+    help: Option<String>,
+    segments: Segments,
+    required: bool,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl TimeInput {
+    /// Creates a new time field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            segments: Segments::new(false),
+            required: false,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Adds a seconds segment.
+    pub fn seconds(mut self) -> Self {
+        self.segments.seconds = true;
+        self.segments.rebuild();
+        self
+    }
+
+    /// Shows hours as 1-12 with an AM/PM segment (toggled with Up/Down, `a`
+    /// or `p`). The exported value is still 24-hour.
+    pub fn twelve_hour(mut self) -> Self {
+        self.segments.twelve_hour = true;
+        self.segments.rebuild();
+        self
+    }
+
+    /// Adds an optional `±HH:MM` timezone offset. Typing `+` or `-` jumps to
+    /// it, and `z` sets UTC.
+    pub fn timezone_offset(mut self) -> Self {
+        self.segments.offset = true;
+        self.segments.rebuild();
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the initial value (`HH:MM[:SS][±HH:MM]`, 24-hour).
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.segments.initial = Some(value.into());
+        self.segments.rebuild();
+        self
+    }
+}
+
+impl Field for TimeInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        render_segmented(
+            &self.label,
+            self.required,
+            &self.segments,
+            &self.validation_errors,
+            area,
+            buf,
+            focused,
+            style,
+        );
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        self.segments.handle_input(event)
+    }
+
+    fn value(&self) -> Value {
+        match self.segments.to_iso() {
+            Ok(Some(iso)) => Value::String(iso),
+            _ => Value::Null,
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        validate_segmented(&self.id, &self.label, self.required, &self.segments)
+    }
+
+    fn height(&self) -> u16 {
+        if self.validation_errors.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
+
+/// A combined date and time field edited one segment at a time.
+///
+/// Works like [`TimeInput`] with leading year, month and day segments; days
+/// are checked against the real calendar. The value is exported as an ISO
+/// 8601 date-time such as `"2024-03-15T14:30"` or
+/// `"2024-03-15T14:30:00+02:00"`.
+pub struct DateTimeInput {
+    id: String,
+    label: String,
+    help: Option<String>,
+    segments: Segments,
+    required: bool,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl DateTimeInput {
+    /// Creates a new date-time field.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            segments: Segments::new(true),
+            required: false,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Adds a seconds segment.
+    pub fn seconds(mut self) -> Self {
+        self.segments.seconds = true;
+        self.segments.rebuild();
+        self
+    }
+
+    /// Shows hours as 1-12 with an AM/PM segment. The exported value is still
+    /// 24-hour.
+    pub fn twelve_hour(mut self) -> Self {
+        self.segments.twelve_hour = true;
+        self.segments.rebuild();
+        self
+    }
+
+    /// Adds an optional `±HH:MM` timezone offset.
+    pub fn timezone_offset(mut self) -> Self {
+        self.segments.offset = true;
+        self.segments.rebuild();
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the initial value (`YYYY-MM-DDTHH:MM[:SS][±HH:MM]`, 24-hour).
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.segments.initial = Some(value.into());
+        self.segments.rebuild();
+        self
+    }
+}
+
+impl Field for DateTimeInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        render_segmented(
+            &self.label,
+            self.required,
+            &self.segments,
+            &self.validation_errors,
+            area,
+            buf,
+            focused,
+            style,
+        );
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        self.segments.handle_input(event)
+    }
+
+    fn value(&self) -> Value {
+        match self.segments.to_iso() {
+            Ok(Some(iso)) => Value::String(iso),
+            _ => Value::Null,
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        validate_segmented(&self.id, &self.label, self.required, &self.segments)
+    }
+
+    fn height(&self) -> u16 {
+        if self.validation_errors.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(field: &mut dyn Field, code: KeyCode) {
+        field.handle_input(&KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    fn type_keys(field: &mut dyn Field, keys: &str) {
+        for c in keys.chars() {
+            press(field, KeyCode::Char(c));
+        }
+    }
+
+    fn iso(value: &str) -> Value {
+        Value::String(value.to_string())
+    }
+
+    #[test]
+    fn typing_fills_segments_and_advances() {
+        let mut time = TimeInput::new("t", "Time");
+        type_keys(&mut time, "0930");
+        assert_eq!(time.value(), iso("09:30"));
+    }
+
+    #[test]
+    fn overflowing_digit_starts_the_segment_over() {
+        let mut segments = Segments::new(false);
+        segments.type_digit(2);
+        assert_eq!(segments.active, 0);
+        // 25 is not an hour, so the 5 starts over as 05 and moves on
+        segments.type_digit(5);
+        assert_eq!(segments.get(SegmentKind::Hour), Some(5));
+        assert_eq!(segments.active, 1);
+
+        let mut time = TimeInput::new("t", "Time");
+        type_keys(&mut time, "257");
+        assert_eq!(time.value(), iso("05:07"));
+    }
+
+    #[test]
+    fn twelve_hour_typing_converts_to_24_hour() {
+        let mut time = TimeInput::new("t", "Time").twelve_hour();
+        type_keys(&mut time, "1230a");
+        assert_eq!(time.value(), iso("00:30"));
+
+        let mut time = TimeInput::new("t", "Time").twelve_hour();
+        type_keys(&mut time, "1230p");
+        assert_eq!(time.value(), iso("12:30"));
+
+        let mut time = TimeInput::new("t", "Time").twelve_hour();
+        type_keys(&mut time, "0745p");
+        assert_eq!(time.value(), iso("19:45"));
+
+        // 13 is not a 12-hour hour, so it restarts as 03
+        let mut time = TimeInput::new("t", "Time").twelve_hour();
+        type_keys(&mut time, "1300a");
+        assert_eq!(time.value(), iso("03:00"));
+    }
+
+    #[test]
+    fn twelve_hour_loads_24_hour_values() {
+        for (value, hour, meridiem) in [
+            ("00:15", 12, 0),
+            ("11:59", 11, 0),
+            ("12:45", 12, 1),
+            ("23:05", 11, 1),
+        ] {
+            let time = TimeInput::new("t", "Time")
+                .twelve_hour()
+                .initial_value(value);
+            assert_eq!(
+                time.segments.get(SegmentKind::Hour),
+                Some(hour),
+                "{}",
+                value
+            );
+            assert_eq!(
+                time.segments.get(SegmentKind::Meridiem),
+                Some(meridiem),
+                "{}",
+                value
+            );
+            assert_eq!(time.value(), iso(value));
+        }
+    }
+
+    #[test]
+    fn builder_order_does_not_matter() {
+        let time = TimeInput::new("t", "Time")
+            .initial_value("18:20:05")
+            .seconds()
+            .twelve_hour();
+        assert_eq!(time.value(), iso("18:20:05"));
+    }
+
+    #[test]
+    fn day_is_bounded_by_the_month() {
+        // 2023 is not a leap year, so 29 overflows and the 9 starts over
+        let mut date_time = DateTimeInput::new("dt", "When");
+        type_keys(&mut date_time, "202302291000");
+        assert_eq!(date_time.value(), iso("2023-02-09T10:00"));
+
+        let mut date_time = DateTimeInput::new("dt", "When");
+        type_keys(&mut date_time, "202402291000");
+        assert_eq!(date_time.value(), iso("2024-02-29T10:00"));
+    }
+
+    #[test]
+    fn stepping_wraps_within_bounds() {
+        let mut time = TimeInput::new("t", "Time").initial_value("23:00");
+        press(&mut time, KeyCode::Up);
+        assert_eq!(time.value(), iso("00:00"));
+        press(&mut time, KeyCode::Down);
+        assert_eq!(time.value(), iso("23:00"));
+
+        let mut date_time = DateTimeInput::new("dt", "When").initial_value("2024-03-31T08:00");
+        press(&mut date_time, KeyCode::Right);
+        press(&mut date_time, KeyCode::Down);
+        // Stepping the month leaves the day alone, so February 31 is flagged
+        assert_eq!(date_time.segments.to_iso(), Err("Invalid date".to_string()));
+    }
+
+    #[test]
+    fn incomplete_and_offset_values() {
+        let mut time = TimeInput::new("t", "Time");
+        type_keys(&mut time, "12");
+        assert_eq!(time.value(), Value::Null);
+        assert!(time.validate().is_err());
+
+        let mut time = TimeInput::new("t", "Time").timezone_offset();
+        type_keys(&mut time, "0930-0530");
+        assert_eq!(time.value(), iso("09:30-05:30"));
+
+        let mut time = TimeInput::new("t", "Time").timezone_offset();
+        type_keys(&mut time, "0930z");
+        assert_eq!(time.value(), iso("09:30+00:00"));
+    }
+}
@@ -0,0 +1,207 @@
+//! On/off switch field.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::field::{render_error_line, Field};
+use crate::style::FormStyle;
+use crate::validation::ValidationError;
+
+/// An on/off switch, rendered as `◯━━ Off` / `━━◉ On`.
+///
+/// Enter or Space flips the switch; Left turns it off and Right turns it on.
+/// The value is exported as a bool.
+pub struct Toggle {
+    id: String,
+    label: String,
+    help: Option<String>,
+    on: bool,
+    on_label: String,
+    off_label: String,
+    required: bool,
+    validation_errors: Vec<ValidationError>,
+}
+
+impl Toggle {
+    /// Creates a new toggle field, initially off.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            help: None,
+            on: false,
+            on_label: "On".to_string(),
+            off_label: "Off".to_string(),
+            required: false,
+            validation_errors: Vec::new(),
+        }
+    }
+
+    /// Sets the initial state.
+    pub fn initial_value(mut self, on: bool) -> Self {
+        self.on = on;
+        self
+    }
+
+    /// Sets the text shown next to the switch in each state (default "On" / "Off").
+    pub fn labels(mut self, on: impl Into<String>, off: impl Into<String>) -> Self {
+        self.on_label = on.into();
+        self.off_label = off.into();
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Marks this field as required (must be switched on).
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+}
+
+impl Field for Toggle {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, focused: bool, style: &FormStyle) {
+        if area.height < 1 || area.width < 1 {
+            return;
+        }
+
+        // Render label
+        let label_style = if focused {
+            style.label_focused
+        } else {
+            style.label
+        };
+
+        let required_marker = if self.required { "*" } else { "" };
+        let label_text = format!("{}{}: ", self.label, required_marker);
+        let label_width = label_text.width().min(area.width as usize);
+
+        let label_span = Span::styled(&label_text, label_style);
+        let label_line = Line::from(label_span);
+        let label_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: label_width as u16,
+            height: 1,
+        };
+        label_line.render(label_area, buf);
+
+        // Calculate switch area
+        let input_x = area.x + label_width as u16;
+        let input_width = area.width.saturating_sub(label_width as u16);
+
+        if input_width == 0 {
+            return;
+        }
+
+        let switch_style = if focused {
+            style.input_focused
+        } else {
+            style.input
+        };
+
+        let (switch, state) = if self.on {
+            ("━━◉", &self.on_label)
+        } else {
+            ("◯━━", &self.off_label)
+        };
+        let line = Line::from(vec![
+            Span::styled(switch, switch_style),
+            Span::styled(format!(" {}", state), style.label),
+        ]);
+        line.render(
+            Rect {
+                x: input_x,
+                y: area.y,
+                width: input_width,
+                height: 1,
+            },
+            buf,
+        );
+
+        // Render validation errors below the switch
+        if area.height > 1 {
+            let error_area = Rect {
+                x: input_x,
+                y: area.y + 1,
+                width: input_width,
+                height: 1,
+            };
+            render_error_line(&self.validation_errors, error_area, buf, style);
+        }
+    }
+
+    fn handle_input(&mut self, event: &KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Enter | KeyCode::Char(' ') => self.on = !self.on,
+            KeyCode::Left => self.on = false,
+            KeyCode::Right => self.on = true,
+            _ => return false,
+        }
+        true
+    }
+
+    fn value(&self) -> Value {
+        Value::Bool(self.on)
+    }
+
+    fn display_value(&self) -> String {
+        if self.on {
+            self.on_label.clone()
+        } else {
+            self.off_label.clone()
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        if self.required && !self.on {
+            Err(vec![ValidationError {
+                field_id: self.id.clone(),
+                message: format!("{} must be switched on", self.label),
+            }])
+        } else {
+            Ok(())
+        }
+    }
+
+    fn height(&self) -> u16 {
+        if self.validation_errors.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn set_errors(&mut self, errors: Vec<ValidationError>) {
+        self.validation_errors = errors;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.validation_errors
+    }
+}
@@ -2,21 +2,39 @@
 
 use std::fs::File;
 use std::io::{self, Write};
+use std::ops::Range;
 use std::path::Path;
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Padding, Widget};
+use ratatui::widgets::{
+    Block, Borders, Cell, Padding, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
+    StatefulWidget, Table, TableState, Tabs, Widget,
+};
 use serde_json::{Map, Value};
+use unicode_width::UnicodeWidthStr;
 
 use crate::block::Block as FormBlock;
-use crate::field::{Checkbox, Field, Select, TextInput};
+use crate::field::{
+    wrap_text, Autocomplete, CheckList, Checkbox, Confirm, DatePicker, DateTimeInput, Divider,
+    Field, Heading, Number, OptionsProvider, Paragraph, PathInput, RadioGroup, Select, Slider,
+    Suggest, TagInput, TextArea, TextInput, TimeInput, Toggle,
+};
 use crate::navigation::FocusManager;
 use crate::style::FormStyle;
 use crate::validation::ValidationError;
 
+/// Placeholder written in place of sensitive values by [`Form::to_json_redacted`].
+const REDACTED: &str = "[REDACTED]";
+
+/// Decides from the form's current values whether a field is shown.
+type Condition = Arc<dyn Fn(&Value) -> bool + Send + Sync>;
+
 /// Result of form submission.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FormResult {
@@ -28,14 +46,36 @@ pub enum FormResult {
     Active,
 }
 
+/// A page of a multi-page form, or a tab of a tabbed one.
+struct Page {
+    title: String,
+    fields: Range<usize>,
+}
+
 /// A form with fields and navigation.
 pub struct Form {
     title: Option<String>,
     fields: Vec<Box<dyn Field>>,
+    pages: Vec<Page>, // empty for a single-page form
+    current_page: usize,
+    back_selected: bool, // Back rather than Next is selected in the button row
+    tabbed: bool,        // pages are shown as tabs rather than wizard steps
+    next_tab_key: KeyEvent,
+    previous_tab_key: KeyEvent,
+    review: bool,
+    reviewing: bool,
+    review_selected: usize, // row on the review screen; the row count = Confirm
+    conditions: Vec<(usize, Condition)>, // field index and when it is shown
+    hidden: Vec<bool>,
+    null_hidden_fields: bool,
     focus_manager: FocusManager,
     style: FormStyle,
     result: FormResult,
     validation_errors: Vec<ValidationError>,
+    // Updated during render, which only has shared access to the form.
+    scroll_offset: AtomicU16,
+    viewport_height: AtomicU16,
+    viewport_width: AtomicU16,
 }
 
 impl Form {
@@ -54,36 +94,91 @@ impl Form {
         self.result == FormResult::Active
     }
 
+    /// Returns whether the review screen is shown.
+    pub fn is_reviewing(&self) -> bool {
+        self.reviewing
+    }
+
     /// Handles keyboard input.
     pub fn handle_input(&mut self, event: KeyEvent) {
+        self.handle_key(event);
+        self.update_fields();
+        self.update_visibility();
+    }
+
+    fn handle_key(&mut self, event: KeyEvent) {
+        if self.reviewing {
+            self.handle_review_input(&event);
+            return;
+        }
+
+        if self.tabbed {
+            if key_matches(&event, &self.next_tab_key) {
+                self.switch_tab(true);
+                return;
+            }
+            if key_matches(&event, &self.previous_tab_key) {
+                self.switch_tab(false);
+                return;
+            }
+        }
+
         // Handle global keys
         match event.code {
             KeyCode::Esc => {
-                self.result = FormResult::Cancelled;
+                // Let an open dropdown close before cancelling the form
+                if !self.delegate_to_focused_field(&event) {
+                    self.result = FormResult::Cancelled;
+                }
+                return;
+            }
+            KeyCode::Tab if event.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.move_focus(false);
                 return;
             }
             KeyCode::Tab => {
-                if event.modifiers.contains(KeyModifiers::SHIFT) {
-                    self.focus_manager.focus_previous();
-                } else {
-                    self.focus_manager.focus_next();
+                // Let an open suggestion list complete before moving on
+                if !self.delegate_to_focused_field(&event) {
+                    self.move_focus(true);
                 }
                 return;
             }
+            KeyCode::BackTab => {
+                self.move_focus(false);
+                return;
+            }
             KeyCode::Enter if self.focus_manager.is_submit_focused() => {
-                self.try_submit();
+                if !self.is_wizard() {
+                    self.try_submit();
+                } else if self.back_selected {
+                    self.previous_page();
+                } else {
+                    self.next_page();
+                }
+                return;
+            }
+            KeyCode::Left | KeyCode::Right
+                if self.focus_manager.is_submit_focused() && self.is_wizard() =>
+            {
+                self.back_selected = event.code == KeyCode::Left && self.current_page > 0;
                 return;
             }
             KeyCode::Down => {
                 // Only move focus if the current field doesn't consume the event
                 if !self.delegate_to_focused_field(&event) {
-                    self.focus_manager.focus_next();
+                    self.move_focus(true);
                 }
                 return;
             }
             KeyCode::Up => {
                 if !self.delegate_to_focused_field(&event) {
-                    self.focus_manager.focus_previous();
+                    self.move_focus(false);
+                }
+                return;
+            }
+            KeyCode::PageDown | KeyCode::PageUp => {
+                if !self.delegate_to_focused_field(&event) {
+                    self.focus_page(event.code == KeyCode::PageDown);
                 }
                 return;
             }
@@ -94,49 +189,332 @@ impl Form {
         self.delegate_to_focused_field(&event);
     }
 
+    /// Moves focus forward or backward, stepping through the parts of a
+    /// composite field before leaving it.
+    fn move_focus(&mut self, forward: bool) {
+        if !self.focus_manager.is_submit_focused() {
+            if let Some(field) = self.fields.get_mut(self.focus_manager.current_index()) {
+                let moved = if forward {
+                    field.focus_next()
+                } else {
+                    field.focus_previous()
+                };
+                if moved {
+                    return;
+                }
+            }
+        }
+
+        if forward {
+            self.focus_manager.focus_next();
+        } else {
+            self.focus_manager.focus_previous();
+        }
+        self.enter_focused_field(!forward);
+    }
+
+    /// Tells the newly focused field which end it was entered from.
+    fn enter_focused_field(&mut self, backward: bool) {
+        if self.focus_manager.is_submit_focused() {
+            return;
+        }
+        if let Some(field) = self.fields.get_mut(self.focus_manager.current_index()) {
+            field.focus_enter(backward);
+        }
+    }
+
     fn delegate_to_focused_field(&mut self, event: &KeyEvent) -> bool {
         if self.focus_manager.is_submit_focused() {
             return false;
         }
 
         let index = self.focus_manager.current_index();
-        if let Some(field) = self.fields.get_mut(index) {
-            field.handle_input(event)
-        } else {
-            false
+        let Some(field) = self.fields.get_mut(index) else {
+            return false;
+        };
+
+        let consumed = field.handle_input(event);
+        if consumed && !field.errors().is_empty() {
+            self.revalidate_field(index);
         }
+        consumed
+    }
+
+    /// Re-runs validation for a field that is showing errors, so messages
+    /// disappear as soon as the user fixes the value.
+    fn revalidate_field(&mut self, index: usize) {
+        let field = &mut self.fields[index];
+        let mut stale_ids: Vec<String> =
+            field.errors().iter().map(|e| e.field_id.clone()).collect();
+        stale_ids.push(field.id().to_string());
+
+        let errors = field.validate().err().unwrap_or_default();
+        field.set_errors(errors.clone());
+
+        self.validation_errors
+            .retain(|error| !stale_ids.contains(&error.field_id));
+        self.validation_errors.extend(errors);
     }
 
     fn try_submit(&mut self) {
+        match self.validate_fields(0..self.fields.len()) {
+            // Focus on the first field with an error
+            Some(index) => self.focus_field_at(index),
+            None if self.review => {
+                self.reviewing = true;
+                self.review_selected = self.review_rows().len();
+            }
+            None => self.result = FormResult::Submitted,
+        }
+    }
+
+    /// Focuses the field at `index`, switching to its page or tab if needed.
+    fn focus_field_at(&mut self, index: usize) {
+        if let Some(page) = self.pages.iter().position(|p| p.fields.contains(&index)) {
+            if page != self.current_page {
+                self.show_page(page);
+            }
+        }
+        self.focus_manager.focus_field(index);
+        self.enter_focused_field(false);
+    }
+
+    /// Returns the indices of the fields listed on the review screen, which
+    /// leaves out hidden fields and display-only content.
+    fn review_rows(&self) -> Vec<usize> {
+        (0..self.fields.len())
+            .filter(|&i| !self.hidden[i] && !self.fields[i].entries().is_empty())
+            .collect()
+    }
+
+    fn handle_review_input(&mut self, event: &KeyEvent) {
+        let confirm_row = self.review_rows().len();
+        match event.code {
+            KeyCode::Up | KeyCode::BackTab => {
+                self.review_selected = self.review_selected.saturating_sub(1);
+            }
+            KeyCode::Tab if event.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.review_selected = self.review_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.review_selected = (self.review_selected + 1).min(confirm_row);
+            }
+            KeyCode::Home => self.review_selected = 0,
+            KeyCode::End => self.review_selected = confirm_row,
+            KeyCode::Enter if self.review_selected >= confirm_row => {
+                self.result = FormResult::Submitted;
+            }
+            KeyCode::Enter => {
+                // Jump back to the form to edit the selected field
+                let index = self.review_rows()[self.review_selected];
+                self.reviewing = false;
+                self.focus_field_at(index);
+            }
+            KeyCode::Esc => {
+                self.reviewing = false;
+                self.focus_manager.focus_submit();
+            }
+            _ => {}
+        }
+    }
+
+    /// Validates the fields in `range`, showing their errors. Returns the
+    /// index of the first invalid field.
+    fn validate_fields(&mut self, range: Range<usize>) -> Option<usize> {
         self.validation_errors.clear();
+        let mut first_invalid = None;
 
-        for field in &self.fields {
-            if let Err(errors) = field.validate() {
-                self.validation_errors.extend(errors);
+        for (i, field) in self.fields[range.clone()].iter_mut().enumerate() {
+            if self.hidden[range.start + i] {
+                field.clear_errors();
+                continue;
             }
+            match field.validate() {
+                Ok(()) => field.clear_errors(),
+                Err(errors) => {
+                    first_invalid.get_or_insert(range.start + i);
+                    self.validation_errors.extend(errors.iter().cloned());
+                    field.set_errors(errors);
+                }
+            }
+        }
+
+        first_invalid
+    }
+
+    /// Validates the current page and moves to the next one, or submits the
+    /// form from the last page.
+    fn next_page(&mut self) {
+        if self.current_page + 1 >= self.pages.len() {
+            self.try_submit();
+            return;
+        }
+
+        match self.validate_fields(self.visible_fields()) {
+            Some(index) => self.focus_manager.focus_field(index),
+            None => self.show_page(self.current_page + 1),
+        }
+    }
+
+    /// Returns to the previous page, keeping the Back button selected.
+    fn previous_page(&mut self) {
+        if self.current_page == 0 {
+            return;
         }
+        self.validation_errors.clear();
+        self.show_page(self.current_page - 1);
+        self.focus_manager.focus_submit();
+        self.back_selected = self.current_page > 0;
+    }
+
+    /// Returns whether the form is a wizard with Back/Next buttons.
+    fn is_wizard(&self) -> bool {
+        !self.pages.is_empty() && !self.tabbed
+    }
 
-        if self.validation_errors.is_empty() {
-            self.result = FormResult::Submitted;
+    /// Switches to the next or previous tab, wrapping around at either end.
+    fn switch_tab(&mut self, forward: bool) {
+        let count = self.pages.len();
+        if count < 2 {
+            return;
+        }
+        let tab = if forward {
+            (self.current_page + 1) % count
         } else {
-            // Focus on the first field with an error
-            if let Some(error) = self.validation_errors.first() {
-                for (i, field) in self.fields.iter().enumerate() {
-                    if field.id() == error.field_id {
-                        self.focus_manager.focus_field(i);
-                        break;
-                    }
-                }
+            (self.current_page + count - 1) % count
+        };
+        self.show_page(tab);
+    }
+
+    /// Switches to `page` and focuses its first field, or for a tab the field
+    /// that was focused when it was last shown.
+    fn show_page(&mut self, page: usize) {
+        if self.tabbed {
+            self.focus_manager.save_focus(self.current_page);
+        }
+        self.current_page = page;
+        self.back_selected = false;
+        self.scroll_offset.store(0, Ordering::Relaxed);
+        self.refresh_focusable();
+
+        if self.tabbed && self.focus_manager.restore_focus(page) {
+            return;
+        }
+        self.focus_first_field();
+    }
+
+    /// Focuses the first shown field on the current page or tab, or the
+    /// submit button if there is none.
+    fn focus_first_field(&mut self) {
+        match self
+            .visible_fields()
+            .find(|&i| self.focus_manager.is_focusable(i))
+        {
+            Some(index) => {
+                self.focus_manager.focus_field(index);
+                self.enter_focused_field(false);
+            }
+            None => self.focus_manager.focus_submit(),
+        }
+    }
+
+    /// Lets focus reach the shown fields on the current page or tab.
+    fn refresh_focusable(&mut self) {
+        let range = self.visible_fields();
+        let focusable = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| range.contains(&i) && !self.hidden[i] && field.is_focusable())
+            .collect();
+        self.focus_manager.set_focusable(focusable);
+    }
+
+    /// Returns every field's value, hidden or not, keyed like [`Form::to_json`].
+    fn current_values(&self) -> Value {
+        let mut values = Map::new();
+        for field in &self.fields {
+            values.extend(field.entries());
+        }
+        Value::Object(values)
+    }
+
+    /// Lets fields that depend on other fields, such as a [`Select`] with an
+    /// options provider, react to the current values.
+    fn update_fields(&mut self) {
+        let values = self.current_values();
+        for field in &mut self.fields {
+            field.update(&values);
+        }
+    }
+
+    /// Re-evaluates the `visible_when` conditions against the current values.
+    /// Fields that become hidden lose their errors and focus.
+    fn update_visibility(&mut self) {
+        if self.conditions.is_empty() {
+            return;
+        }
+
+        let values = self.current_values();
+
+        let mut hidden = vec![false; self.fields.len()];
+        for (index, condition) in &self.conditions {
+            hidden[*index] |= !condition(&values);
+        }
+        if hidden == self.hidden {
+            return;
+        }
+
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            if hidden[i] && !self.hidden[i] {
+                // Composites report errors under their sub-field ids
+                let mut stale_ids: Vec<String> =
+                    field.errors().iter().map(|e| e.field_id.clone()).collect();
+                stale_ids.push(field.id().to_string());
+                field.clear_errors();
+                self.validation_errors
+                    .retain(|error| !stale_ids.contains(&error.field_id));
             }
         }
+        self.hidden = hidden;
+        self.refresh_focusable();
+    }
+
+    /// Returns the indices of the fields on the current page or tab.
+    fn visible_fields(&self) -> Range<usize> {
+        self.pages
+            .get(self.current_page)
+            .map_or(0..self.fields.len(), |page| page.fields.clone())
     }
 
     /// Returns the form data as a JSON object.
     pub fn to_json(&self) -> Value {
+        self.collect_json(false)
+    }
+
+    /// Returns the form data as a JSON object with the values of sensitive
+    /// fields, such as passwords, replaced by `"[REDACTED]"`.
+    pub fn to_json_redacted(&self) -> Value {
+        self.collect_json(true)
+    }
+
+    fn collect_json(&self, redact: bool) -> Value {
         let mut map = Map::new();
 
-        for field in &self.fields {
-            map.insert(field.id().to_string(), field.value());
+        for (i, field) in self.fields.iter().enumerate() {
+            if self.hidden[i] && !self.null_hidden_fields {
+                continue;
+            }
+            for (key, value) in field.entries() {
+                let value = if self.hidden[i] {
+                    Value::Null
+                } else if redact && field.is_sensitive() {
+                    Value::String(REDACTED.to_string())
+                } else {
+                    value
+                };
+                map.insert(key, value);
+            }
         }
 
         Value::Object(map)
@@ -157,6 +535,9 @@ impl Form {
     }
 
     /// Renders the form to a buffer.
+    ///
+    /// When the fields do not fit in the available height, the form scrolls so
+    /// that the focused field stays visible and a scrollbar is drawn on the right.
     pub fn render(&self, area: Rect, buf: &mut Buffer) {
         // Create the outer block with border
         let border_style = if self.focus_manager.is_submit_focused() {
@@ -181,30 +562,28 @@ impl Form {
             return;
         }
 
-        // Layout for fields and submit button
-        let field_count = self.fields.len();
-        let mut constraints = Vec::with_capacity(field_count + 2);
-
-        for field in &self.fields {
-            constraints.push(Constraint::Length(field.height()));
-        }
-        constraints.push(Constraint::Length(1)); // Spacer
-        constraints.push(Constraint::Length(1)); // Submit button
-        constraints.push(Constraint::Min(0)); // Remaining space
-
-        let layout = Layout::vertical(constraints).split(inner_area);
+        // Keep the bottom row free for the validation summary
+        let summary_height = u16::from(!self.validation_errors.is_empty());
+        let viewport = Rect {
+            height: inner_area.height - summary_height,
+            ..inner_area
+        };
+        self.viewport_height
+            .store(viewport.height, Ordering::Relaxed);
+        self.viewport_width.store(viewport.width, Ordering::Relaxed);
 
-        // Render each field
-        for (i, field) in self.fields.iter().enumerate() {
-            let is_focused =
-                !self.focus_manager.is_submit_focused() && i == self.focus_manager.current_index();
-            field.render(layout[i], buf, is_focused, &self.style);
+        if self.reviewing {
+            self.render_review(viewport, buf);
+            return;
         }
 
-        // Render submit button
-        let submit_idx = field_count + 1;
-        if submit_idx < layout.len() {
-            self.render_submit_button(layout[submit_idx], buf);
+        let width = self.content_width(viewport.width, viewport.height);
+        let content_height = self.content_height(width);
+        if content_height <= viewport.height {
+            self.scroll_offset.store(0, Ordering::Relaxed);
+            self.render_content(viewport, buf);
+        } else {
+            self.render_scrolled(viewport, width, content_height, buf);
         }
 
         // Render validation errors summary if any
@@ -228,102 +607,698 @@ impl Form {
         }
     }
 
-    fn render_submit_button(&self, area: Rect, buf: &mut Buffer) {
-        let is_focused = self.focus_manager.is_submit_focused();
-        let style = if is_focused {
-            self.style.button_focused
+    /// Returns the width left for fields in a viewport of the given size,
+    /// which loses a column to the scrollbar when the content does not fit.
+    fn content_width(&self, viewport_width: u16, viewport_height: u16) -> u16 {
+        // Narrower content is never shorter, so this settles in one step
+        if self.content_height(viewport_width) > viewport_height {
+            viewport_width.saturating_sub(1)
         } else {
-            self.style.button
-        };
+            viewport_width
+        }
+    }
 
-        let text = if is_focused {
-            "[ Submit ]"
+    /// Returns the total height of the step indicator or tabs, fields, spacer
+    /// and buttons, laid out `width` columns wide.
+    fn content_height(&self, width: u16) -> u16 {
+        self.visible_fields()
+            .map(|index| self.field_height(index, width))
+            .sum::<u16>()
+            .saturating_add(self.header_height() + 2)
+    }
+
+    /// Returns the rows taken by the step indicator of a multi-page form, or
+    /// the tab bar of a tabbed one.
+    fn header_height(&self) -> u16 {
+        if self.pages.is_empty() {
+            0
         } else {
-            "  Submit  "
-        };
+            2
+        }
+    }
 
-        // Center the button
-        let button_width = text.len() as u16;
-        let x = area.x + (area.width.saturating_sub(button_width)) / 2;
+    /// Returns the help text lines shown below the field at `index`, which
+    /// are only shown while it is focused.
+    fn help_lines(&self, index: usize, width: u16) -> Vec<String> {
+        if self.focus_manager.is_submit_focused() || index != self.focus_manager.current_index() {
+            return Vec::new();
+        }
+        self.fields[index]
+            .help_text()
+            .map_or_else(Vec::new, |help| wrap_text(help, width as usize))
+    }
 
-        for (i, c) in text.chars().enumerate() {
-            if x + (i as u16) < area.x + area.width {
-                buf[(x + i as u16, area.y)].set_char(c);
-                buf[(x + i as u16, area.y)].set_style(style);
-            }
+    /// Returns the rows taken by the field at `index`, including its help
+    /// text, or 0 if it is hidden.
+    fn field_height(&self, index: usize, width: u16) -> u16 {
+        if self.hidden[index] {
+            return 0;
         }
+        self.fields[index]
+            .height_for(width)
+            .saturating_add(self.help_lines(index, width).len() as u16)
     }
-}
 
-/// Builder for creating forms.
-pub struct FormBuilder {
-    title: Option<String>,
-    fields: Vec<Box<dyn Field>>,
-    style: FormStyle,
-}
+    /// Returns the content rows `(top, height)` occupied by the focused element
+    /// when laid out `width` columns wide.
+    fn focused_rows(&self, width: u16) -> (u16, u16) {
+        if self.focus_manager.is_submit_focused() {
+            return (self.content_height(width) - 1, 1);
+        }
 
-impl FormBuilder {
-    /// Creates a new form builder.
-    pub fn new() -> Self {
-        Self {
-            title: None,
-            fields: Vec::new(),
-            style: FormStyle::default(),
+        let index = self.focus_manager.current_index();
+        let visible = self.visible_fields();
+        let top = (visible.start..index.min(visible.end))
+            .map(|i| self.field_height(i, width))
+            .sum::<u16>()
+            + self.header_height();
+        let Some(field) = self.fields.get(index) else {
+            return (top, 1);
+        };
+        // Scroll to the focused part of a composite such as a repeat block
+        match field.focused_rows(width) {
+            Some((row, height)) => {
+                // Help is drawn below the whole field, so keep it in view too
+                let help = self.help_lines(index, width).len() as u16;
+                let height = if help > 0 {
+                    field.height_for(width) - row + help
+                } else {
+                    height
+                };
+                (top + row, height)
+            }
+            None => (top, self.field_height(index, width)),
         }
     }
 
-    /// Sets the form title.
-    pub fn title(mut self, title: impl Into<String>) -> Self {
-        self.title = Some(title.into());
-        self
-    }
+    /// Renders fields and the submit button from the top of `area`.
+    fn render_content(&self, area: Rect, buf: &mut Buffer) {
+        let visible = self.visible_fields();
+        let field_count = visible.len();
+        let mut constraints = Vec::with_capacity(field_count + 4);
 
-    /// Sets the form style.
-    pub fn style(mut self, style: FormStyle) -> Self {
-        self.style = style;
-        self
-    }
+        constraints.push(Constraint::Length(self.header_height())); // Step indicator or tabs
+        for i in visible.clone() {
+            constraints.push(Constraint::Length(self.field_height(i, area.width)));
+        }
+        constraints.push(Constraint::Length(1)); // Spacer
+        constraints.push(Constraint::Length(1)); // Submit button
+        constraints.push(Constraint::Min(0)); // Remaining space
 
-    /// Starts building a text field.
-    pub fn text(self, id: impl Into<String>, label: impl Into<String>) -> TextFieldBuilder {
-        TextFieldBuilder::new(self, id.into(), label.into())
-    }
+        let layout = Layout::vertical(constraints).split(area);
 
-    /// Starts building a select field.
-    pub fn select(self, id: impl Into<String>, label: impl Into<String>) -> SelectFieldBuilder {
-        SelectFieldBuilder::new(self, id.into(), label.into())
-    }
+        if self.tabbed {
+            self.render_tabs(layout[0], buf);
+        } else if !self.pages.is_empty() {
+            self.render_step_indicator(layout[0], buf);
+        }
+        let layout = &layout[1..];
 
-    /// Starts building a checkbox field.
-    pub fn checkbox(self, id: impl Into<String>, label: impl Into<String>) -> CheckboxFieldBuilder {
-        CheckboxFieldBuilder::new(self, id.into(), label.into())
-    }
+        // Render each field on the current page
+        for (row, i) in visible.enumerate() {
+            if self.hidden[i] {
+                continue;
+            }
+            let field = &self.fields[i];
+            let is_focused =
+                !self.focus_manager.is_submit_focused() && i == self.focus_manager.current_index();
+            let field_area = Rect {
+                height: field.height_for(area.width).min(layout[row].height),
+                ..layout[row]
+            };
+            field.render(field_area, buf, is_focused, &self.style);
+
+            // Render help text below the focused field
+            let help_top = field_area.bottom();
+            for (line_index, line) in self.help_lines(i, area.width).iter().enumerate() {
+                let y = help_top + line_index as u16;
+                if y >= layout[row].bottom() {
+                    break;
+                }
+                buf.set_stringn(
+                    layout[row].x,
+                    y,
+                    line,
+                    layout[row].width as usize,
+                    self.style.help,
+                );
+            }
+        }
 
-    /// Adds a pre-built field.
-    pub fn field(mut self, field: Box<dyn Field>) -> Self {
-        self.fields.push(field);
-        self
+        // Render submit button
+        let submit_idx = field_count + 1;
+        if submit_idx < layout.len() {
+            self.render_submit_button(layout[submit_idx], buf);
+        }
     }
 
-    /// Adds all fields from a block.
-    pub fn block(mut self, block: impl FormBlock) -> Self {
-        for field in block.fields() {
-            self.fields.push(field);
+    /// Renders the content into an off-screen buffer and copies the rows
+    /// around the focused element into `viewport`, with a scrollbar.
+    fn render_scrolled(&self, viewport: Rect, width: u16, content_height: u16, buf: &mut Buffer) {
+        let content_area = Rect {
+            x: viewport.x,
+            y: 0,
+            width,
+            height: content_height,
+        };
+        let mut content = Buffer::empty(content_area);
+        self.render_content(content_area, &mut content);
+
+        // Scroll just enough to bring the focused element into view
+        let max_offset = content_height - viewport.height;
+        let (top, height) = self.focused_rows(width);
+        let mut offset = self.scroll_offset.load(Ordering::Relaxed);
+        if top + height > offset + viewport.height {
+            offset = (top + height).saturating_sub(viewport.height);
         }
-        self
-    }
+        if top < offset {
+            offset = top;
+        }
+        let offset = offset.min(max_offset);
+        self.scroll_offset.store(offset, Ordering::Relaxed);
+
+        for row in 0..viewport.height {
+            for x in content_area.left()..content_area.right() {
+                buf[(x, viewport.y + row)] = content[(x, offset + row)].clone();
+            }
+        }
+
+        let scrollbar_area = Rect {
+            x: viewport.right().saturating_sub(1),
+            width: 1,
+            ..viewport
+        };
+        let mut scrollbar_state = ScrollbarState::new(max_offset as usize + 1)
+            .position(offset as usize)
+            .viewport_content_length(viewport.height as usize);
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_style(self.style.border)
+            .thumb_style(self.style.border_focused)
+            .render(scrollbar_area, buf, &mut scrollbar_state);
+    }
+
+    /// Moves focus roughly one viewport height up or down.
+    fn focus_page(&mut self, down: bool) {
+        let viewport_height = self.viewport_height.load(Ordering::Relaxed);
+        let width =
+            self.content_width(self.viewport_width.load(Ordering::Relaxed), viewport_height);
+        let page = viewport_height.max(1);
+        let visible = self.visible_fields();
+        let (first, field_count) = (visible.start, visible.end);
+        let mut tops = vec![0; first];
+        let mut y = self.header_height();
+        for i in visible {
+            tops.push(y);
+            y = y.saturating_add(self.field_height(i, width));
+        }
+
+        let (current_top, _) = self.focused_rows(width);
+        let current = if self.focus_manager.is_submit_focused() {
+            field_count
+        } else {
+            self.focus_manager.current_index()
+        };
+
+        if down {
+            let target = current_top.saturating_add(page);
+            let next = tops
+                .iter()
+                .rposition(|top| *top <= target)
+                .map_or(field_count, |i| i.max(current + 1));
+            // Skip display-only content such as headings
+            match (next..field_count).find(|&i| self.focus_manager.is_focusable(i)) {
+                Some(next) => {
+                    self.focus_manager.focus_field(next);
+                    self.enter_focused_field(false);
+                }
+                None => self.focus_manager.focus_submit(),
+            }
+        } else if current > first {
+            let target = current_top.saturating_sub(page);
+            let previous = tops[first..]
+                .iter()
+                .position(|top| *top >= target)
+                .map_or(current - 1, |i| (first + i).min(current - 1));
+            let focusable = (first..=previous)
+                .rev()
+                .find(|&i| self.focus_manager.is_focusable(i))
+                .or_else(|| (previous..current).find(|&i| self.focus_manager.is_focusable(i)));
+            if let Some(previous) = focusable {
+                self.focus_manager.focus_field(previous);
+                self.enter_focused_field(true);
+            }
+        }
+    }
+
+    /// Renders the read-only table of labels and values, followed by the
+    /// Confirm button.
+    fn render_review(&self, area: Rect, buf: &mut Buffer) {
+        let [header_area, _, table_area, _, button_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        Line::from(vec![
+            Span::styled("Review your answers", self.style.title),
+            Span::styled(
+                "  Enter edits a field · Esc returns to the form",
+                self.style.help,
+            ),
+        ])
+        .render(header_area, buf);
+
+        let rows = self.review_rows();
+        let label_width = rows
+            .iter()
+            .map(|&i| self.fields[i].label().width())
+            .max()
+            .unwrap_or(0) as u16;
+        let table_rows = rows.iter().map(|&i| {
+            let field = &self.fields[i];
+            let value = if field.is_sensitive() {
+                "••••••••".to_string()
+            } else {
+                field.display_value()
+            };
+            Row::new(vec![
+                Cell::from(Span::styled(field.label().to_string(), self.style.label)),
+                Cell::from(Span::styled(value, self.style.label)),
+            ])
+        });
+        let table = Table::new(
+            table_rows,
+            [Constraint::Length(label_width), Constraint::Min(0)],
+        )
+        .column_spacing(2)
+        .row_highlight_style(self.style.input_focused);
+        let selected = (self.review_selected < rows.len()).then_some(self.review_selected);
+        let mut state = TableState::default().with_selected(selected);
+        StatefulWidget::render(table, table_area, buf, &mut state);
+
+        let confirm_focused = self.review_selected >= rows.len();
+        let (text, style) = if confirm_focused {
+            ("[ Confirm ]", self.style.button_focused)
+        } else {
+            ("  Confirm  ", self.style.button)
+        };
+        Line::from(Span::styled(text, style))
+            .centered()
+            .render(button_area, buf);
+    }
+
+    /// Renders the page titles, highlighting the current one, or just the
+    /// current step when they do not fit.
+    fn render_step_indicator(&self, area: Rect, buf: &mut Buffer) {
+        let mut spans = Vec::new();
+        for (i, page) in self.pages.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(" › ", self.style.border));
+            }
+            let style = match i.cmp(&self.current_page) {
+                std::cmp::Ordering::Less => self.style.label,
+                std::cmp::Ordering::Equal => self.style.title,
+                std::cmp::Ordering::Greater => self.style.placeholder,
+            };
+            spans.push(Span::styled(format!("{}. {}", i + 1, page.title), style));
+        }
+
+        let mut line = Line::from(spans);
+        if line.width() > area.width as usize {
+            line = Line::from(Span::styled(
+                format!(
+                    "Step {} of {}: {}",
+                    self.current_page + 1,
+                    self.pages.len(),
+                    self.pages[self.current_page].title
+                ),
+                self.style.title,
+            ));
+        }
+        line.render(Rect { height: 1, ..area }, buf);
+    }
+
+    /// Renders the tab titles, highlighting the current tab.
+    fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
+        Tabs::new(self.pages.iter().map(|page| page.title.as_str()))
+            .select(self.current_page)
+            .style(self.style.label)
+            .highlight_style(self.style.title.add_modifier(Modifier::REVERSED))
+            .divider(Span::styled("│", self.style.border))
+            .render(Rect { height: 1, ..area }, buf);
+    }
+
+    /// Renders Back and Next (or Submit on the last page) for a multi-page form.
+    fn render_page_buttons(&self, area: Rect, buf: &mut Buffer) {
+        let is_focused = self.focus_manager.is_submit_focused();
+        let last_page = self.current_page + 1 >= self.pages.len();
+        let forward = if last_page { "Submit" } else { "Next" };
+
+        let mut buttons = Vec::new();
+        if self.current_page > 0 {
+            buttons.push(("Back", is_focused && self.back_selected));
+        }
+        buttons.push((forward, is_focused && !self.back_selected));
+
+        let mut spans = Vec::new();
+        for (i, (text, selected)) in buttons.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("  "));
+            }
+            let (text, style) = if selected {
+                (format!("[ {} ]", text), self.style.button_focused)
+            } else {
+                (format!("  {}  ", text), self.style.button)
+            };
+            spans.push(Span::styled(text, style));
+        }
+
+        Line::from(spans).centered().render(area, buf);
+    }
+
+    fn render_submit_button(&self, area: Rect, buf: &mut Buffer) {
+        if self.is_wizard() {
+            self.render_page_buttons(area, buf);
+            return;
+        }
+
+        let is_focused = self.focus_manager.is_submit_focused();
+        let style = if is_focused {
+            self.style.button_focused
+        } else {
+            self.style.button
+        };
+
+        let text = if is_focused {
+            "[ Submit ]"
+        } else {
+            "  Submit  "
+        };
+
+        // Center the button
+        let button_width = text.len() as u16;
+        let x = area.x + (area.width.saturating_sub(button_width)) / 2;
+
+        for (i, c) in text.chars().enumerate() {
+            if x + (i as u16) < area.x + area.width {
+                buf[(x + i as u16, area.y)].set_char(c);
+                buf[(x + i as u16, area.y)].set_style(style);
+            }
+        }
+    }
+}
+
+/// Returns whether `event` is the key combination `key`.
+fn key_matches(event: &KeyEvent, key: &KeyEvent) -> bool {
+    event.code == key.code && event.modifiers == key.modifiers
+}
+
+/// Builder for creating forms.
+pub struct FormBuilder {
+    title: Option<String>,
+    fields: Vec<Box<dyn Field>>,
+    pages: Vec<(String, usize)>, // page title and index of its first field
+    tabbed: bool,
+    next_tab_key: KeyEvent,
+    previous_tab_key: KeyEvent,
+    conditions: Vec<(usize, Condition)>,
+    null_hidden_fields: bool,
+    review: bool,
+    style: FormStyle,
+}
+
+impl FormBuilder {
+    /// Creates a new form builder.
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            fields: Vec::new(),
+            pages: Vec::new(),
+            tabbed: false,
+            next_tab_key: KeyEvent::new(KeyCode::PageDown, KeyModifiers::CONTROL),
+            previous_tab_key: KeyEvent::new(KeyCode::PageUp, KeyModifiers::CONTROL),
+            conditions: Vec::new(),
+            null_hidden_fields: false,
+            review: false,
+            style: FormStyle::default(),
+        }
+    }
+
+    /// Sets the form title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the form style.
+    pub fn style(mut self, style: FormStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Starts building a text field.
+    pub fn text(self, id: impl Into<String>, label: impl Into<String>) -> TextFieldBuilder {
+        TextFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a password field. The value is masked with `•`.
+    pub fn password(self, id: impl Into<String>, label: impl Into<String>) -> TextFieldBuilder {
+        let mut builder = TextFieldBuilder::new(self, id.into(), label.into());
+        builder.field = builder.field.masked('•');
+        builder
+    }
+
+    /// Starts building a multi-line text area field.
+    pub fn textarea(self, id: impl Into<String>, label: impl Into<String>) -> TextAreaFieldBuilder {
+        TextAreaFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a numeric field.
+    pub fn number(self, id: impl Into<String>, label: impl Into<String>) -> NumberFieldBuilder {
+        NumberFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a select field.
+    pub fn select(self, id: impl Into<String>, label: impl Into<String>) -> SelectFieldBuilder {
+        SelectFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a radio button group.
+    pub fn radio(self, id: impl Into<String>, label: impl Into<String>) -> RadioFieldBuilder {
+        RadioFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a checkbox field.
+    pub fn checkbox(self, id: impl Into<String>, label: impl Into<String>) -> CheckboxFieldBuilder {
+        CheckboxFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a slider field.
+    pub fn slider(self, id: impl Into<String>, label: impl Into<String>) -> SliderFieldBuilder {
+        SliderFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building an on/off toggle field.
+    pub fn toggle(self, id: impl Into<String>, label: impl Into<String>) -> ToggleFieldBuilder {
+        ToggleFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a Yes/No confirm field.
+    pub fn confirm(self, id: impl Into<String>, label: impl Into<String>) -> ConfirmFieldBuilder {
+        ConfirmFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a multi-select checklist field.
+    pub fn checklist(
+        self,
+        id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> CheckListFieldBuilder {
+        CheckListFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a date picker field.
+    pub fn date(self, id: impl Into<String>, label: impl Into<String>) -> DateFieldBuilder {
+        DateFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a time-of-day field.
+    pub fn time(self, id: impl Into<String>, label: impl Into<String>) -> TimeFieldBuilder {
+        TimeFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a combined date and time field.
+    pub fn datetime(self, id: impl Into<String>, label: impl Into<String>) -> DateTimeFieldBuilder {
+        DateTimeFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building an autocomplete text field.
+    pub fn autocomplete(
+        self,
+        id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> AutocompleteFieldBuilder {
+        AutocompleteFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a filesystem path field.
+    pub fn path(self, id: impl Into<String>, label: impl Into<String>) -> PathFieldBuilder {
+        PathFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Starts building a tag (chip list) field.
+    pub fn tags(self, id: impl Into<String>, label: impl Into<String>) -> TagFieldBuilder {
+        TagFieldBuilder::new(self, id.into(), label.into())
+    }
+
+    /// Exports fields hidden by a `visible_when` condition as `null` instead
+    /// of leaving them out of the JSON output.
+    pub fn null_hidden_fields(mut self) -> Self {
+        self.null_hidden_fields = true;
+        self
+    }
+
+    /// Shows a review screen after a successful submit. It lists every
+    /// field's label and value; Enter on a row returns to that field for
+    /// editing, and the form is only submitted after Confirm.
+    pub fn review(mut self) -> Self {
+        self.review = true;
+        self
+    }
+
+    /// Starts a new page. Fields added after this call appear on the page,
+    /// turning the form into a wizard with Back/Next buttons and a step
+    /// indicator. Each page is validated before moving on. Fields added before
+    /// the first page are placed on it.
+    pub fn page(mut self, title: impl Into<String>) -> Self {
+        self.pages.push((title.into(), self.fields.len()));
+        self
+    }
+
+    /// Starts a new tab. Fields added after this call appear on the tab,
+    /// and a tab bar is shown above the fields. Unlike pages, tabs can be
+    /// visited in any order and share a single Submit button, which validates
+    /// every tab. Fields added before the first tab are placed on it.
+    ///
+    /// A form uses either pages or tabs: once `tab()` is called, pages
+    /// started with [`page`](Self::page) are shown as tabs too.
+    pub fn tab(mut self, title: impl Into<String>) -> Self {
+        self.pages.push((title.into(), self.fields.len()));
+        self.tabbed = true;
+        self
+    }
+
+    /// Sets the keys that switch to the next and previous tab (default
+    /// Ctrl+PageDown / Ctrl+PageUp).
+    pub fn tab_keys(mut self, next: KeyEvent, previous: KeyEvent) -> Self {
+        self.next_tab_key = next;
+        self.previous_tab_key = previous;
+        self
+    }
+
+    /// Adds a pre-built field.
+    pub fn field(mut self, field: Box<dyn Field>) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Adds a section heading. Headings are not focusable and are left out
+    /// of the JSON output.
+    pub fn heading(mut self, text: impl Into<String>) -> Self {
+        self.fields.push(Box::new(Heading::new(text)));
+        self
+    }
+
+    /// Adds a paragraph of explanatory text, wrapped to the form's width.
+    pub fn paragraph(mut self, text: impl Into<String>) -> Self {
+        self.fields.push(Box::new(Paragraph::new(text)));
+        self
+    }
+
+    /// Adds a horizontal divider.
+    pub fn divider(mut self) -> Self {
+        self.fields.push(Box::new(Divider::new()));
+        self
+    }
+
+    /// Adds all fields from a block, preceded by a heading if the block has a title.
+    pub fn block(mut self, block: impl FormBlock) -> Self {
+        if let Some(title) = block.title() {
+            self.fields.push(Box::new(Heading::new(title)));
+        }
+        for field in block.fields() {
+            self.fields.push(field);
+        }
+        self
+    }
+
+    /// Adds a block that is only shown while `predicate` returns true for the
+    /// form's current values, as with a field builder's `visible_when`.
+    pub fn block_visible_when(
+        mut self,
+        block: impl FormBlock,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let start = self.fields.len();
+        self = self.block(block);
+        let condition: Condition = Arc::new(predicate);
+        for index in start..self.fields.len() {
+            self.conditions.push((index, condition.clone()));
+        }
+        self
+    }
 
     /// Builds the form.
     pub fn build(self) -> Form {
         let field_count = self.fields.len();
-        Form {
+        let mut pages: Vec<Page> = Vec::with_capacity(self.pages.len());
+        for (title, start) in self.pages.into_iter().rev() {
+            let end = pages.last().map_or(field_count, |next| next.fields.start);
+            pages.push(Page {
+                title,
+                fields: start..end,
+            });
+        }
+        pages.reverse();
+        if let Some(first) = pages.first_mut() {
+            first.fields.start = 0;
+        }
+
+        let focus_manager = FocusManager::new(field_count);
+        let mut form = Form {
             title: self.title,
             fields: self.fields,
-            focus_manager: FocusManager::new(field_count),
+            pages,
+            current_page: 0,
+            back_selected: false,
+            tabbed: self.tabbed,
+            next_tab_key: self.next_tab_key,
+            previous_tab_key: self.previous_tab_key,
+            review: self.review,
+            reviewing: false,
+            review_selected: 0,
+            conditions: self.conditions,
+            hidden: vec![false; field_count],
+            null_hidden_fields: self.null_hidden_fields,
+            focus_manager,
             style: self.style,
             result: FormResult::Active,
             validation_errors: Vec::new(),
-        }
+            scroll_offset: AtomicU16::new(0),
+            viewport_height: AtomicU16::new(0),
+            viewport_width: AtomicU16::new(0),
+        };
+        // Work out which fields are shown before choosing the initial focus
+        form.update_fields();
+        form.update_visibility();
+        form.refresh_focusable();
+        form.focus_first_field();
+        form
     }
 }
 
@@ -365,12 +1340,45 @@ impl TextFieldBuilder {
         self
     }
 
+    /// Masks the value with the given character.
+    pub fn masked(mut self, mask: char) -> Self {
+        self.field = self.field.masked(mask);
+        self
+    }
+
+    /// Allows Ctrl+R to temporarily reveal a masked value.
+    pub fn revealable(mut self) -> Self {
+        self.field = self.field.revealable();
+        self
+    }
+
     /// Adds a validator.
     pub fn validator(mut self, validator: Box<dyn crate::validation::Validator>) -> Self {
         self.field = self.field.validator(validator);
         self
     }
 
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
     /// Finishes building this field and returns to the form builder.
     pub fn done(mut self) -> FormBuilder {
         self.form_builder.fields.push(Box::new(self.field));
@@ -378,29 +1386,35 @@ impl TextFieldBuilder {
     }
 }
 
-/// Builder for select fields.
-pub struct SelectFieldBuilder {
+/// Builder for text area fields.
+pub struct TextAreaFieldBuilder {
     form_builder: FormBuilder,
-    field: Select,
+    field: TextArea,
 }
 
-impl SelectFieldBuilder {
+impl TextAreaFieldBuilder {
     fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
         Self {
             form_builder,
-            field: Select::new(id, label),
+            field: TextArea::new(id, label),
         }
     }
 
-    /// Adds an option.
-    pub fn option(mut self, value: impl Into<String>, display: impl Into<String>) -> Self {
-        self.field = self.field.option(value, display);
+    /// Sets a placeholder.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.field = self.field.placeholder(placeholder);
         self
     }
 
-    /// Adds multiple options.
-    pub fn options(mut self, options: Vec<(impl Into<String>, impl Into<String>)>) -> Self {
-        self.field = self.field.options(options);
+    /// Sets the number of visible rows.
+    pub fn rows(mut self, rows: u16) -> Self {
+        self.field = self.field.rows(rows);
+        self
+    }
+
+    /// Limits the number of lines that can be entered.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.field = self.field.max_lines(max_lines);
         self
     }
 
@@ -411,11 +1425,38 @@ impl SelectFieldBuilder {
     }
 
     /// Sets the initial value.
-    pub fn initial_value(mut self, value: &str) -> Self {
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
         self.field = self.field.initial_value(value);
         self
     }
 
+    /// Adds a validator.
+    pub fn validator(mut self, validator: Box<dyn crate::validation::Validator>) -> Self {
+        self.field = self.field.validator(validator);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
     /// Finishes building this field and returns to the form builder.
     pub fn done(mut self) -> FormBuilder {
         self.form_builder.fields.push(Box::new(self.field));
@@ -423,29 +1464,1019 @@ impl SelectFieldBuilder {
     }
 }
 
-/// Builder for checkbox fields.
-pub struct CheckboxFieldBuilder {
+/// Builder for numeric fields.
+pub struct NumberFieldBuilder {
     form_builder: FormBuilder,
-    field: Checkbox,
+    field: Number,
 }
 
-impl CheckboxFieldBuilder {
+impl NumberFieldBuilder {
     fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
         Self {
             form_builder,
-            field: Checkbox::new(id, label),
+            field: Number::new(id, label),
         }
     }
 
-    /// Sets the initial checked state.
-    pub fn checked(mut self, checked: bool) -> Self {
-        self.field = self.field.checked(checked);
+    /// Allows decimal values.
+    pub fn decimal(mut self) -> Self {
+        self.field = self.field.decimal();
         self
     }
 
-    /// Marks the field as required (must be checked).
-    pub fn required(mut self) -> Self {
-        self.field = self.field.required();
+    /// Sets the minimum value.
+    pub fn min(mut self, min: f64) -> Self {
+        self.field = self.field.min(min);
+        self
+    }
+
+    /// Sets the maximum value.
+    pub fn max(mut self, max: f64) -> Self {
+        self.field = self.field.max(max);
+        self
+    }
+
+    /// Sets the increment step.
+    pub fn step(mut self, step: f64) -> Self {
+        self.field = self.field.step(step);
+        self
+    }
+
+    /// Sets a placeholder.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.field = self.field.placeholder(placeholder);
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: f64) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for select fields.
+pub struct SelectFieldBuilder {
+    form_builder: FormBuilder,
+    field: Select,
+}
+
+impl SelectFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: Select::new(id, label),
+        }
+    }
+
+    /// Adds an option.
+    pub fn option(mut self, value: impl Into<String>, display: impl Into<String>) -> Self {
+        self.field = self.field.option(value, display);
+        self
+    }
+
+    /// Adds multiple options.
+    pub fn options(mut self, options: Vec<(impl Into<String>, impl Into<String>)>) -> Self {
+        self.field = self.field.options(options);
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: &str) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Enables or disables type-to-filter.
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.field = self.field.filterable(filterable);
+        self
+    }
+
+    /// Computes the options from the form's current values, e.g. from the
+    /// country chosen in another select. An option that is no longer offered
+    /// is deselected.
+    pub fn options_from(mut self, provider: impl OptionsProvider + 'static) -> Self {
+        self.field = self.field.options_from(provider);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for radio button groups.
+pub struct RadioFieldBuilder {
+    form_builder: FormBuilder,
+    field: RadioGroup,
+}
+
+impl RadioFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: RadioGroup::new(id, label),
+        }
+    }
+
+    /// Adds an option.
+    pub fn option(mut self, value: impl Into<String>, display: impl Into<String>) -> Self {
+        self.field = self.field.option(value, display);
+        self
+    }
+
+    /// Adds multiple options.
+    pub fn options(mut self, options: Vec<(impl Into<String>, impl Into<String>)>) -> Self {
+        self.field = self.field.options(options);
+        self
+    }
+
+    /// Lays the options out on a single row.
+    pub fn horizontal(mut self) -> Self {
+        self.field = self.field.horizontal();
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: &str) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for checkbox fields.
+pub struct CheckboxFieldBuilder {
+    form_builder: FormBuilder,
+    field: Checkbox,
+}
+
+impl CheckboxFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: Checkbox::new(id, label),
+        }
+    }
+
+    /// Sets the initial checked state.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.field = self.field.checked(checked);
+        self
+    }
+
+    /// Marks the field as required (must be checked).
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for slider fields.
+pub struct SliderFieldBuilder {
+    form_builder: FormBuilder,
+    field: Slider,
+}
+
+impl SliderFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: Slider::new(id, label),
+        }
+    }
+
+    /// Sets the minimum value (default 0).
+    pub fn min(mut self, min: f64) -> Self {
+        self.field = self.field.min(min);
+        self
+    }
+
+    /// Sets the maximum value (default 100).
+    pub fn max(mut self, max: f64) -> Self {
+        self.field = self.field.max(max);
+        self
+    }
+
+    /// Sets the amount moved by Left/Right (default 1).
+    pub fn step(mut self, step: f64) -> Self {
+        self.field = self.field.step(step);
+        self
+    }
+
+    /// Sets the amount moved by PageUp/PageDown.
+    pub fn page_step(mut self, step: f64) -> Self {
+        self.field = self.field.page_step(step);
+        self
+    }
+
+    /// Adds a labelled tick; the slider then only stops on ticks.
+    pub fn tick(mut self, value: f64, label: impl Into<String>) -> Self {
+        self.field = self.field.tick(value, label);
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: f64) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for toggle fields.
+pub struct ToggleFieldBuilder {
+    form_builder: FormBuilder,
+    field: Toggle,
+}
+
+impl ToggleFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: Toggle::new(id, label),
+        }
+    }
+
+    /// Sets the initial state.
+    pub fn initial_value(mut self, on: bool) -> Self {
+        self.field = self.field.initial_value(on);
+        self
+    }
+
+    /// Sets the text shown next to the switch in each state.
+    pub fn labels(mut self, on: impl Into<String>, off: impl Into<String>) -> Self {
+        self.field = self.field.labels(on, off);
+        self
+    }
+
+    /// Marks the field as required (must be switched on).
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for confirm fields.
+pub struct ConfirmFieldBuilder {
+    form_builder: FormBuilder,
+    field: Confirm,
+}
+
+impl ConfirmFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: Confirm::new(id, label),
+        }
+    }
+
+    /// Sets the initial answer.
+    pub fn initial_value(mut self, answer: bool) -> Self {
+        self.field = self.field.initial_value(answer);
+        self
+    }
+
+    /// Sets the text of the two choices.
+    pub fn labels(mut self, yes: impl Into<String>, no: impl Into<String>) -> Self {
+        self.field = self.field.labels(yes, no);
+        self
+    }
+
+    /// Marks the field as required (must be answered).
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for checklist fields.
+pub struct CheckListFieldBuilder {
+    form_builder: FormBuilder,
+    field: CheckList,
+}
+
+impl CheckListFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: CheckList::new(id, label),
+        }
+    }
+
+    /// Adds an option.
+    pub fn option(mut self, value: impl Into<String>, display: impl Into<String>) -> Self {
+        self.field = self.field.option(value, display);
+        self
+    }
+
+    /// Adds multiple options.
+    pub fn options(mut self, options: Vec<(impl Into<String>, impl Into<String>)>) -> Self {
+        self.field = self.field.options(options);
+        self
+    }
+
+    /// Requires at least `min` checked options.
+    pub fn min_selected(mut self, min: usize) -> Self {
+        self.field = self.field.min_selected(min);
+        self
+    }
+
+    /// Allows at most `max` checked options.
+    pub fn max_selected(mut self, max: usize) -> Self {
+        self.field = self.field.max_selected(max);
+        self
+    }
+
+    /// Marks the field as required (at least one option checked).
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initially checked values.
+    pub fn initial_values(mut self, values: &[&str]) -> Self {
+        self.field = self.field.initial_values(values);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for date picker fields.
+pub struct DateFieldBuilder {
+    form_builder: FormBuilder,
+    field: DatePicker,
+}
+
+impl DateFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: DatePicker::new(id, label),
+        }
+    }
+
+    /// Sets the earliest allowed date (`YYYY-MM-DD`).
+    ///
+    /// # Panics
+    /// Panics if `date` is not a valid date.
+    pub fn min_date(mut self, date: &str) -> Self {
+        self.field = self.field.min_date(date);
+        self
+    }
+
+    /// Sets the latest allowed date (`YYYY-MM-DD`).
+    ///
+    /// # Panics
+    /// Panics if `date` is not a valid date.
+    pub fn max_date(mut self, date: &str) -> Self {
+        self.field = self.field.max_date(date);
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial value (`YYYY-MM-DD`).
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for time fields.
+pub struct TimeFieldBuilder {
+    form_builder: FormBuilder,
+    field: TimeInput,
+}
+
+impl TimeFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: TimeInput::new(id, label),
+        }
+    }
+
+    /// Adds a seconds segment.
+    pub fn seconds(mut self) -> Self {
+        self.field = self.field.seconds();
+        self
+    }
+
+    /// Shows hours as 1-12 with an AM/PM segment.
+    pub fn twelve_hour(mut self) -> Self {
+        self.field = self.field.twelve_hour();
+        self
+    }
+
+    /// Adds an optional `±HH:MM` timezone offset.
+    pub fn timezone_offset(mut self) -> Self {
+        self.field = self.field.timezone_offset();
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial value (`HH:MM[:SS][±HH:MM]`, 24-hour).
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for date-time fields.
+pub struct DateTimeFieldBuilder {
+    form_builder: FormBuilder,
+    field: DateTimeInput,
+}
+
+impl DateTimeFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: DateTimeInput::new(id, label),
+        }
+    }
+
+    /// Adds a seconds segment.
+    pub fn seconds(mut self) -> Self {
+        self.field = self.field.seconds();
+        self
+    }
+
+    /// Shows hours as 1-12 with an AM/PM segment.
+    pub fn twelve_hour(mut self) -> Self {
+        self.field = self.field.twelve_hour();
+        self
+    }
+
+    /// Adds an optional `±HH:MM` timezone offset.
+    pub fn timezone_offset(mut self) -> Self {
+        self.field = self.field.timezone_offset();
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial value (`YYYY-MM-DDTHH:MM[:SS][±HH:MM]`, 24-hour).
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for autocomplete fields.
+pub struct AutocompleteFieldBuilder {
+    form_builder: FormBuilder,
+    field: Autocomplete,
+}
+
+impl AutocompleteFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: Autocomplete::new(id, label),
+        }
+    }
+
+    /// Sets the suggestion provider: a `Vec<String>` or a closure
+    /// `Fn(&str) -> Vec<String>`.
+    pub fn suggestions(mut self, provider: impl Suggest + 'static) -> Self {
+        self.field = self.field.suggestions(provider);
+        self
+    }
+
+    /// Only accepts values that are one of the suggestions.
+    pub fn strict(mut self) -> Self {
+        self.field = self.field.strict();
+        self
+    }
+
+    /// Sets the placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.field = self.field.placeholder(placeholder);
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Adds a validator.
+    pub fn validator(mut self, validator: Box<dyn crate::validation::Validator>) -> Self {
+        self.field = self.field.validator(validator);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for filesystem path fields.
+pub struct PathFieldBuilder {
+    form_builder: FormBuilder,
+    field: PathInput,
+}
+
+impl PathFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: PathInput::new(id, label),
+        }
+    }
+
+    /// Only suggests directories.
+    pub fn directories_only(mut self) -> Self {
+        self.field = self.field.directories_only();
+        self
+    }
+
+    /// Only suggests files with one of the given extensions.
+    pub fn extensions(mut self, extensions: &[&str]) -> Self {
+        self.field = self.field.extensions(extensions);
+        self
+    }
+
+    /// Sets the placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.field = self.field.placeholder(placeholder);
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.field = self.field.initial_value(value);
+        self
+    }
+
+    /// Adds a validator.
+    pub fn validator(mut self, validator: Box<dyn crate::validation::Validator>) -> Self {
+        self.field = self.field.validator(validator);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
+        self
+    }
+
+    /// Finishes building this field and returns to the form builder.
+    pub fn done(mut self) -> FormBuilder {
+        self.form_builder.fields.push(Box::new(self.field));
+        self.form_builder
+    }
+}
+
+/// Builder for tag fields.
+pub struct TagFieldBuilder {
+    form_builder: FormBuilder,
+    field: TagInput,
+}
+
+impl TagFieldBuilder {
+    fn new(form_builder: FormBuilder, id: String, label: String) -> Self {
+        Self {
+            form_builder,
+            field: TagInput::new(id, label),
+        }
+    }
+
+    /// Sets the placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.field = self.field.placeholder(placeholder);
+        self
+    }
+
+    /// Adds a validator that every item must pass.
+    pub fn item_validator(mut self, validator: Box<dyn crate::validation::Validator>) -> Self {
+        self.field = self.field.item_validator(validator);
+        self
+    }
+
+    /// Requires at least `min` items.
+    pub fn min_items(mut self, min: usize) -> Self {
+        self.field = self.field.min_items(min);
+        self
+    }
+
+    /// Allows at most `max` items.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.field = self.field.max_items(max);
+        self
+    }
+
+    /// Enables or disables dropping duplicate items (enabled by default).
+    pub fn dedupe(mut self, dedupe: bool) -> Self {
+        self.field = self.field.dedupe(dedupe);
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self) -> Self {
+        self.field = self.field.required();
+        self
+    }
+
+    /// Sets the initial items.
+    pub fn initial_values(mut self, values: &[&str]) -> Self {
+        self.field = self.field.initial_values(values);
+        self
+    }
+
+    /// Sets a description shown below the field while it is focused.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.field = self.field.help(help);
+        self
+    }
+
+    /// Shows the field only while `predicate` returns true. It receives the
+    /// current values of every field as a JSON object keyed like
+    /// [`Form::to_json`]. Hidden fields are skipped by navigation and
+    /// validation, and left out of the JSON output.
+    pub fn visible_when(
+        mut self,
+        predicate: impl Fn(&Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.form_builder.fields.len();
+        self.form_builder
+            .conditions
+            .push((index, Arc::new(predicate)));
         self
     }
 
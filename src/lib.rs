@@ -8,12 +8,21 @@
 //!
 //! ## Features
 //!
-//! - **Builder API** — chain `.text()`, `.select()`, `.checkbox()`, `.block()` to assemble a form.
-//! - **Fields** — [`TextInput`], [`Select`] (dropdown), [`Checkbox`].
-//! - **Blocks** — [`AddressBlock`], [`ContactBlock`], [`DateRangeBlock`] bundle related fields.
-//! - **Validation** — [`Required`], [`Email`], [`MinLength`], [`MaxLength`], [`Pattern`], or your own [`Validator`].
+//! - **Builder API** — chain `.text()`, `.password()`, `.textarea()`, `.number()`, `.slider()`, `.select()`, `.radio()`, `.checklist()`, `.date()`, `.time()`, `.datetime()`, `.autocomplete()`, `.path()`, `.tags()`, `.checkbox()`, `.toggle()`, `.confirm()`, `.block()` to assemble a form.
+//! - **Fields** — [`TextInput`], [`TextArea`], [`Number`], [`Slider`], [`Select`] (dropdown, cascading via an [`OptionsProvider`]),
+//!   [`RadioGroup`], [`Checkbox`], [`CheckList`] (multi-select), [`DatePicker`],
+//!   [`TimeInput`], [`DateTimeInput`], [`Autocomplete`] (with a [`Suggest`] provider),
+//!   [`PathInput`] (filesystem completion), [`TagInput`] (chip list), [`Toggle`] (on/off switch),
+//!   [`Confirm`] (Yes/No, unanswered until chosen).
+//! - **Blocks** — [`AddressBlock`], [`ContactBlock`], [`DateRangeBlock`] bundle related fields;
+//!   [`RepeatBlock`] repeats any block a variable number of times. A block's title is shown as a heading.
+//! - **Static content** — [`Heading`], [`Paragraph`] and [`Divider`] between fields; not focusable
+//!   and not exported.
+//! - **Validation** — [`Required`], [`Email`], [`MinLength`], [`MaxLength`], [`Pattern`],
+//!   path checks ([`MustExist`], [`IsDir`], [`IsFile`], [`Writable`], [`Extension`]), or your own [`Validator`].
 //! - **Theming** — [`FormStyle::dark`] / [`FormStyle::light`] presets, or override any component style.
-//! - **Output** — [`Form::to_json`] / [`Form::write_json`] for flat JSON export.
+//! - **Output** — [`Form::to_json`] / [`Form::write_json`] for flat JSON export, and
+//!   [`Form::to_json_redacted`] to hide passwords.
 //!
 //! ## Building a form
 //!
@@ -26,12 +35,16 @@
 //!     .text("email", "Email")
 //!         .required()
 //!         .validator(Box::new(Email))
+//!         .help("We'll send the tracking number here")
 //!         .done()
 //!     .block(AddressBlock::new("shipping").required())
 //!     .checkbox("newsletter", "Subscribe to newsletter").done()
 //!     .build();
 //! ```
 //!
+//! Every field builder accepts `.help()`: a description shown dimmed below the
+//! field while it is focused, wrapped to the form's width.
+//!
 //! See `examples/address_form.rs` for a complete event-loop wiring with
 //! `crossterm` + `ratatui::Terminal`.
 //!
@@ -50,6 +63,34 @@
 //! ```
 //!
 //! ```no_run
+//! # use ratatui_form::{Form, MaxLength};
+//! // TextArea (multi-line, Enter inserts a newline)
+//! Form::builder()
+//!     .textarea("description", "Description")
+//!         .rows(5)
+//!         .max_lines(20)
+//!         .validator(Box::new(MaxLength(2000)))
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // Number (exported as a JSON number; Up/Down or +/- step the value)
+//! Form::builder()
+//!     .number("quantity", "Quantity")
+//!         .min(1.0)
+//!         .max(99.0)
+//!         .initial_value(1.0)
+//!         .done()
+//!     .number("price", "Price")
+//!         .decimal()
+//!         .step(0.25)
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
 //! # use ratatui_form::Form;
 //! // Select (dropdown)
 //! Form::builder()
@@ -65,6 +106,39 @@
 //!
 //! ```no_run
 //! # use ratatui_form::Form;
+//! # use serde_json::Value;
+//! // Select whose options depend on another field (see `OptionsProvider`)
+//! Form::builder()
+//!     .select("country", "Country")
+//!         .option("CA", "Canada")
+//!         .option("US", "United States")
+//!         .done()
+//!     .select("region", "Region")
+//!         .options_from(|values: &Value| match values["country"].as_str() {
+//!             Some("CA") => vec![("ON".into(), "Ontario".into())],
+//!             Some("US") => vec![("NY".into(), "New York".into())],
+//!             _ => Vec::new(),
+//!         })
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // RadioGroup (all options visible; arrows move, Space selects)
+//! Form::builder()
+//!     .radio("size", "Size")
+//!         .option("s", "Small")
+//!         .option("m", "Medium")
+//!         .option("l", "Large")
+//!         .horizontal()
+//!         .required()
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
 //! // Checkbox
 //! Form::builder()
 //!     .checkbox("terms", "I agree to the terms")
@@ -73,20 +147,196 @@
 //!     .build();
 //! ```
 //!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // Slider (Left/Right step, PageUp/PageDown page, exported as a number)
+//! Form::builder()
+//!     .slider("volume", "Volume")
+//!         .max(100.0)
+//!         .initial_value(40.0)
+//!         .done()
+//!     .slider("priority", "Priority")
+//!         .min(1.0)
+//!         .max(3.0)
+//!         .tick(1.0, "Low")
+//!         .tick(2.0, "Normal")
+//!         .tick(3.0, "High")
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // Toggle switch (bool) and Yes/No confirm (bool, or null until answered)
+//! Form::builder()
+//!     .toggle("notifications", "Notifications")
+//!         .labels("Enabled", "Disabled")
+//!         .initial_value(true)
+//!         .done()
+//!     .confirm("subscribe", "Subscribe to the newsletter?")
+//!         .required()
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // CheckList (multi-select, exported as a JSON array)
+//! Form::builder()
+//!     .checklist("interests", "Interests")
+//!         .option("rust", "Rust")
+//!         .option("tui", "TUIs")
+//!         .option("web", "Web dev")
+//!         .min_selected(1)
+//!         .max_selected(2)
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // DatePicker (type YYYY-MM-DD or press Enter for a month grid)
+//! Form::builder()
+//!     .date("departure", "Departure")
+//!         .min_date("2024-01-01")
+//!         .max_date("2025-12-31")
+//!         .required()
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // TimeInput / DateTimeInput (segment editing, ISO 8601 output)
+//! Form::builder()
+//!     .time("alarm", "Alarm")
+//!         .twelve_hour()
+//!         .done()
+//!     .datetime("meeting", "Meeting")
+//!         .timezone_offset()
+//!         .required()
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // Autocomplete (suggestions from a Vec<String> or a closure)
+//! let hosts = vec!["db.internal".to_string(), "web.internal".to_string()];
+//! Form::builder()
+//!     .autocomplete("host", "Host")
+//!         .suggestions(hosts)
+//!         .strict()
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::{Form, IsFile};
+//! // PathInput (Tab completes from the filesystem)
+//! Form::builder()
+//!     .path("config", "Config file")
+//!         .extensions(&["toml"])
+//!         .validator(Box::new(IsFile))
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::{Email, Form};
+//! // TagInput (Enter or comma adds a chip, exported as a JSON array)
+//! Form::builder()
+//!     .tags("recipients", "To")
+//!         .item_validator(Box::new(Email))
+//!         .max_items(10)
+//!         .done()
+//!     .build();
+//! ```
+//!
+//! ```no_run
+//! # use ratatui_form::Form;
+//! // Headings, paragraphs and dividers (display-only, not in the JSON output)
+//! Form::builder()
+//!     .heading("Account")
+//!     .paragraph("We only use your email to send receipts.")
+//!     .text("email", "Email").done()
+//!     .divider()
+//!     .build();
+//! ```
+//!
 //! ## Blocks
 //!
 //! Blocks expand into several related fields with sensible validators:
 //!
 //! ```no_run
-//! use ratatui_form::{Form, AddressBlock, ContactBlock, DateRangeBlock};
+//! use ratatui_form::{Form, AddressBlock, ContactBlock, DateRangeBlock, RepeatBlock};
 //!
 //! Form::builder()
 //!     .block(ContactBlock::new("contact").required())      // _name, _email, _phone
 //!     .block(AddressBlock::new("shipping").required())     // _street1.._zip
 //!     .block(DateRangeBlock::new("trip").required())       // _start, _end (YYYY-MM-DD)
+//!     .block(RepeatBlock::new("guests", ContactBlock::new("guest")).max_items(4)) // [{name, ..}]
+//!     .build();
+//! ```
+//!
+//! ## Multi-page forms
+//!
+//! `.page()` splits a form into steps with Back/Next buttons and a step
+//! indicator. Each page is validated before moving on, and the JSON output
+//! merges all pages.
+//!
+//! ```no_run
+//! use ratatui_form::{Form, AddressBlock, ContactBlock};
+//!
+//! Form::builder()
+//!     .page("Contact")
+//!     .block(ContactBlock::new("contact").required())
+//!     .page("Address")
+//!     .block(AddressBlock::new("home"))
+//!     .build();
+//! ```
+//!
+//! ## Tabs
+//!
+//! `.tab()` groups fields into tabs instead of wizard steps. Ctrl+PageDown /
+//! Ctrl+PageUp switch tabs (see [`FormBuilder::tab_keys`]), and a failed submit
+//! switches to the tab holding the first invalid field.
+//!
+//! ```no_run
+//! use ratatui_form::Form;
+//!
+//! Form::builder()
+//!     .tab("General")
+//!     .text("name", "Display name").done()
+//!     .tab("Network")
+//!     .text("proxy", "Proxy").done()
 //!     .build();
 //! ```
 //!
+//! ## Conditional fields
+//!
+//! `.visible_when()` shows a field only while a predicate over the form's
+//! current values holds. Hidden fields are skipped by navigation and
+//! validation, and left out of the JSON output unless
+//! [`FormBuilder::null_hidden_fields`] is set.
+//!
+//! ```no_run
+//! use ratatui_form::Form;
+//!
+//! Form::builder()
+//!     .checkbox("business", "Business account").done()
+//!     .text("company", "Company name").required()
+//!     .visible_when(|values| values["business"] == true)
+//!     .done()
+//!     .build();
+//! ```
+//!
+//! ## Review screen
+//!
+//! `.review()` shows a read-only summary of every answer once the form
+//! validates. Enter on a row returns to that field, and the form is only
+//! submitted after Confirm. Each row shows [`Field::display_value`].
+//!
 //! ## Validation
 //!
 //! Built-in validators live at the crate root. Implement [`Validator`] for custom rules:
@@ -135,10 +385,16 @@ pub mod navigation;
 pub mod style;
 pub mod validation;
 
-pub use block::{AddressBlock, Block, ContactBlock, DateRangeBlock};
-pub use field::{Checkbox, Field, Select, TextInput};
+pub use block::{AddressBlock, Block, ContactBlock, DateRangeBlock, RepeatBlock};
+pub use field::{
+    Autocomplete, CheckList, Checkbox, Confirm, DatePicker, DateTimeInput, Divider, Field, Heading,
+    Number, OptionsProvider, Paragraph, PathInput, RadioGroup, Select, Slider, Suggest, TagInput,
+    TextArea, TextInput, TimeInput, Toggle,
+};
 pub use form::{Form, FormBuilder, FormResult};
 pub use navigation::FocusManager;
 pub use style::FormStyle;
-pub use validation::rules::{Email, MaxLength, MinLength, Pattern, Required};
+pub use validation::rules::{
+    Email, Extension, IsDir, IsFile, MaxLength, MinLength, MustExist, Pattern, Required, Writable,
+};
 pub use validation::{ValidationError, Validator};
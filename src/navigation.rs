@@ -1,10 +1,17 @@
 //! Focus and keyboard navigation management.
 
 /// Manages focus navigation between form fields.
+///
+/// Fields marked as not focusable with [`FocusManager::set_focusable`] are
+/// skipped when moving focus. The focus position can be saved per group of
+/// fields, such as the tabs of a form, and restored when the group is shown
+/// again.
 pub struct FocusManager {
     field_count: usize,
     current_index: usize,
     submit_button_focused: bool,
+    focusable: Vec<bool>,
+    saved: Vec<Option<(usize, bool)>>, // (index, submit focused) per group
 }
 
 impl FocusManager {
@@ -14,6 +21,8 @@ impl FocusManager {
             field_count,
             current_index: 0,
             submit_button_focused: false,
+            focusable: vec![true; field_count],
+            saved: Vec::new(),
         }
     }
 
@@ -27,50 +36,104 @@ impl FocusManager {
         self.submit_button_focused
     }
 
+    /// Returns whether the field at `index` can take focus.
+    pub fn is_focusable(&self, index: usize) -> bool {
+        self.focusable.get(index).copied().unwrap_or(false)
+    }
+
     /// Moves focus to the next field.
     pub fn focus_next(&mut self) {
-        if self.submit_button_focused {
-            // Wrap around to first field
-            self.submit_button_focused = false;
-            self.current_index = 0;
-        } else if self.current_index + 1 >= self.field_count {
-            // Move to submit button
-            self.submit_button_focused = true;
+        let start = if self.submit_button_focused {
+            0
         } else {
-            self.current_index += 1;
+            self.current_index + 1
+        };
+        match (start..self.field_count).find(|&i| self.is_focusable(i)) {
+            Some(index) => {
+                self.current_index = index;
+                self.submit_button_focused = false;
+            }
+            // Past the last field: move to the submit button
+            None if !self.submit_button_focused => self.submit_button_focused = true,
+            // Wrap around to the first field
+            None => {}
         }
     }
 
     /// Moves focus to the previous field.
     pub fn focus_previous(&mut self) {
-        if self.submit_button_focused {
-            // Move back to last field
-            self.submit_button_focused = false;
-            self.current_index = self.field_count.saturating_sub(1);
-        } else if self.current_index > 0 {
-            self.current_index -= 1;
+        let end = if self.submit_button_focused {
+            self.field_count
         } else {
+            self.current_index
+        };
+        match (0..end).rev().find(|&i| self.is_focusable(i)) {
+            Some(index) => {
+                self.current_index = index;
+                self.submit_button_focused = false;
+            }
             // Wrap around to submit button
-            self.submit_button_focused = true;
+            None => self.submit_button_focused = true,
         }
     }
 
     /// Sets the total number of fields.
     pub fn set_field_count(&mut self, count: usize) {
         self.field_count = count;
+        self.focusable.resize(count, true);
         if self.current_index >= count {
             self.current_index = count.saturating_sub(1);
         }
     }
 
-    /// Focuses on a specific field index.
+    /// Sets which fields can take focus, one flag per field. If the current
+    /// field is no longer focusable, focus moves to the next one that is.
+    pub fn set_focusable(&mut self, focusable: Vec<bool>) {
+        self.focusable = focusable;
+        self.focusable.resize(self.field_count, true);
+        if !self.submit_button_focused && !self.is_focusable(self.current_index) {
+            match (self.current_index..self.field_count).find(|&i| self.is_focusable(i)) {
+                Some(index) => self.current_index = index,
+                None => self.submit_button_focused = true,
+            }
+        }
+    }
+
+    /// Focuses on a specific field index. Does nothing if the field is not
+    /// focusable.
     pub fn focus_field(&mut self, index: usize) {
-        if index < self.field_count {
+        if index < self.field_count && self.is_focusable(index) {
             self.current_index = index;
             self.submit_button_focused = false;
         }
     }
 
+    /// Remembers the current focus position for `group`.
+    pub fn save_focus(&mut self, group: usize) {
+        if self.saved.len() <= group {
+            self.saved.resize(group + 1, None);
+        }
+        self.saved[group] = Some((self.current_index, self.submit_button_focused));
+    }
+
+    /// Restores the focus position saved for `group`. Returns false, leaving
+    /// focus unchanged, if nothing was saved or the saved field can no longer
+    /// take focus.
+    pub fn restore_focus(&mut self, group: usize) -> bool {
+        match self.saved.get(group).copied().flatten() {
+            Some((_, true)) => {
+                self.submit_button_focused = true;
+                true
+            }
+            Some((index, false)) if self.is_focusable(index) => {
+                self.current_index = index;
+                self.submit_button_focused = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Focuses on the submit button.
     pub fn focus_submit(&mut self) {
         self.submit_button_focused = true;
@@ -19,6 +19,8 @@ pub struct FormStyle {
     pub placeholder: Style,
     /// Style for error messages.
     pub error: Style,
+    /// Style for help text shown below the focused field.
+    pub help: Style,
     /// Style for the submit button.
     pub button: Style,
     /// Style for the focused submit button.
@@ -43,6 +45,7 @@ impl Default for FormStyle {
             input_focused: Style::default().fg(Color::White).bg(Color::Blue),
             placeholder: Style::default().fg(Color::Gray),
             error: Style::default().fg(Color::Red),
+            help: Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
             button: Style::default().fg(Color::White).bg(Color::DarkGray),
             button_focused: Style::default()
                 .fg(Color::Black)
@@ -102,6 +105,12 @@ impl FormStyle {
         self
     }
 
+    /// Sets the help text style.
+    pub fn help(mut self, style: Style) -> Self {
+        self.help = style;
+        self
+    }
+
     /// Sets the button style.
     pub fn button(mut self, style: Style) -> Self {
         self.button = style;
@@ -133,6 +142,9 @@ impl FormStyle {
             input_focused: Style::default().fg(Color::Black).bg(Color::LightBlue),
             placeholder: Style::default().fg(Color::DarkGray),
             error: Style::default().fg(Color::Red),
+            help: Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM),
             button: Style::default().fg(Color::Black).bg(Color::White),
             button_focused: Style::default()
                 .fg(Color::White)
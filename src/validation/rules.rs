@@ -1,5 +1,8 @@
 //! Built-in validation rules.
 
+use std::fs::{self, Metadata};
+use std::path::{Path, PathBuf};
+
 use crate::validation::Validator;
 
 /// Validates that a field is not empty.
@@ -132,3 +135,170 @@ impl Validator for Pattern {
         }
     }
 }
+
+/// Expands a leading `~/` to the user's home directory.
+pub(crate) fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => Path::new(&home).join(rest),
+        _ if path == "~" => std::env::var_os("HOME").map_or_else(|| path.into(), PathBuf::from),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Validates that a path exists on the local filesystem.
+pub struct MustExist;
+
+impl Validator for MustExist {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(()); // Empty is OK, use Required for that
+        }
+
+        if expand_home(value).exists() {
+            Ok(())
+        } else {
+            Err("Path does not exist".to_string())
+        }
+    }
+}
+
+/// Validates that a path is an existing directory.
+pub struct IsDir;
+
+impl Validator for IsDir {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(()); // Empty is OK, use Required for that
+        }
+
+        if expand_home(value).is_dir() {
+            Ok(())
+        } else {
+            Err("Not a directory".to_string())
+        }
+    }
+}
+
+/// Validates that a path is an existing regular file.
+pub struct IsFile;
+
+impl Validator for IsFile {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(()); // Empty is OK, use Required for that
+        }
+
+        if expand_home(value).is_file() {
+            Ok(())
+        } else {
+            Err("Not a file".to_string())
+        }
+    }
+}
+
+/// Validates that a path can be written: either an existing writable file or
+/// directory, or a new path inside a writable directory.
+///
+/// The check only reads metadata and never opens or creates anything: on Unix
+/// it asks the OS whether the current user has write access, elsewhere it
+/// checks the read-only flag. Anything other than a regular file or a
+/// directory (a FIFO or device, say) is rejected. Permissions can still change
+/// before the actual write, so the caller must handle write errors as well.
+pub struct Writable;
+
+impl Validator for Writable {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(()); // Empty is OK, use Required for that
+        }
+
+        let path = expand_home(value);
+        let target = if path.exists() {
+            path.as_path()
+        } else {
+            match path.parent() {
+                Some(parent) if parent.as_os_str().is_empty() => Path::new("."),
+                Some(parent) => parent,
+                None => return Err("Path is not writable".to_string()),
+            }
+        };
+
+        if can_write(target) {
+            Ok(())
+        } else {
+            Err("Path is not writable".to_string())
+        }
+    }
+}
+
+/// Returns whether the current user can write to the regular file or
+/// directory at `path`.
+fn can_write(path: &Path) -> bool {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.is_file() || metadata.is_dir() => {
+            has_write_access(path, &metadata)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(unix)]
+fn has_write_access(path: &Path, _metadata: &Metadata) -> bool {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    match CString::new(path.as_os_str().as_bytes()) {
+        // SAFETY: `path` is a valid NUL-terminated string for the whole call
+        Ok(path) => unsafe { libc::access(path.as_ptr(), libc::W_OK) == 0 },
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn has_write_access(_path: &Path, metadata: &Metadata) -> bool {
+    !metadata.permissions().readonly()
+}
+
+/// Validates that a path has one of the given file extensions
+/// (case-insensitive, with or without the leading dot).
+pub struct Extension {
+    extensions: Vec<String>,
+}
+
+impl Extension {
+    /// Creates a new extension validator, e.g. `Extension::new(&["toml", "json"])`.
+    pub fn new(extensions: &[&str]) -> Self {
+        Self {
+            extensions: extensions
+                .iter()
+                .map(|ext| ext.trim_start_matches('.').to_lowercase())
+                .collect(),
+        }
+    }
+
+    /// Returns whether `path` has one of the allowed extensions.
+    pub(crate) fn matches(&self, path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| self.extensions.contains(&ext.to_lowercase()))
+    }
+}
+
+impl Validator for Extension {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(()); // Empty is OK, use Required for that
+        }
+
+        if self.matches(Path::new(value)) {
+            Ok(())
+        } else {
+            let list: Vec<String> = self
+                .extensions
+                .iter()
+                .map(|ext| format!(".{}", ext))
+                .collect();
+            Err(format!("Must be a {} file", list.join(", ")))
+        }
+    }
+}